@@ -2,7 +2,14 @@
 
 use crate::addressing::AddressingMode;
 use crate::assembler::{AssemblerError, ErrorType};
-use crate::opcodes::OPCODE_TABLE;
+use crate::opcodes::{canonical_opcode, OpcodeMetadata, OPCODE_TABLE};
+
+/// Find the table index for a mnemonic and addressing mode
+///
+/// See [`canonical_opcode`] for how illegal duplicates are resolved.
+fn lookup_opcode(mnemonic: &str, mode: AddressingMode) -> Option<usize> {
+    canonical_opcode(mnemonic, mode).map(usize::from)
+}
 
 /// Find the opcode metadata for a given mnemonic and addressing mode
 ///
@@ -10,11 +17,9 @@ use crate::opcodes::OPCODE_TABLE;
 pub fn find_opcode_metadata(
     mnemonic: &str,
     mode: AddressingMode,
-) -> Result<&'static crate::opcodes::OpcodeMetadata, AssemblerError> {
-    for (opcode, metadata) in OPCODE_TABLE.iter().enumerate() {
-        if metadata.mnemonic == mnemonic && metadata.addressing_mode == mode {
-            return Ok(&OPCODE_TABLE[opcode]);
-        }
+) -> Result<&'static OpcodeMetadata, AssemblerError> {
+    if let Some(opcode) = lookup_opcode(mnemonic, mode) {
+        return Ok(&OPCODE_TABLE[opcode]);
     }
 
     Err(AssemblerError {
@@ -33,10 +38,8 @@ pub fn find_opcode_metadata(
 ///
 /// Returns the opcode byte or an error if the combination is invalid
 pub fn find_opcode(mnemonic: &str, mode: AddressingMode) -> Result<u8, AssemblerError> {
-    for (opcode, metadata) in OPCODE_TABLE.iter().enumerate() {
-        if metadata.mnemonic == mnemonic && metadata.addressing_mode == mode {
-            return Ok(opcode as u8);
-        }
+    if let Some(opcode) = lookup_opcode(mnemonic, mode) {
+        return Ok(opcode as u8);
     }
 
     Err(AssemblerError {
@@ -69,33 +72,31 @@ pub fn encode_instruction(
 ) -> Result<Vec<u8>, AssemblerError> {
     // Validate operand range based on addressing mode
     match mode {
-        AddressingMode::Immediate => {
-            if operand_value > 0xFF {
-                return Err(AssemblerError {
-                    error_type: ErrorType::RangeError,
-                    line: 0,
-                    column: 0,
-                    span: (0, 0),
-                    message: format!(
-                        "Immediate value ${:04X} exceeds 8-bit range (0-255)",
-                        operand_value
-                    ),
-                });
-            }
+        AddressingMode::Immediate if operand_value > 0xFF => {
+            return Err(AssemblerError {
+                error_type: ErrorType::RangeError,
+                line: 0,
+                column: 0,
+                span: (0, 0),
+                message: format!(
+                    "Immediate value ${:04X} exceeds 8-bit range (0-255)",
+                    operand_value
+                ),
+            });
         }
-        AddressingMode::ZeroPage | AddressingMode::ZeroPageX | AddressingMode::ZeroPageY => {
-            if operand_value > 0xFF {
-                return Err(AssemblerError {
-                    error_type: ErrorType::RangeError,
-                    line: 0,
-                    column: 0,
-                    span: (0, 0),
-                    message: format!(
-                        "Zero-page address ${:04X} exceeds range (0-255)",
-                        operand_value
-                    ),
-                });
-            }
+        AddressingMode::ZeroPage | AddressingMode::ZeroPageX | AddressingMode::ZeroPageY
+            if operand_value > 0xFF =>
+        {
+            return Err(AssemblerError {
+                error_type: ErrorType::RangeError,
+                line: 0,
+                column: 0,
+                span: (0, 0),
+                message: format!(
+                    "Zero-page address ${:04X} exceeds range (0-255)",
+                    operand_value
+                ),
+            });
         }
         _ => {}
     }
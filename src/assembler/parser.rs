@@ -337,10 +337,8 @@ pub fn parse_token_line(tokens: &[Token], line_number: usize) -> Option<Assembly
                             break;
                         }
                         TokenType::Eof => break,
-                        TokenType::Whitespace => {
-                            if !value_str.is_empty() {
-                                value_str.push(' ');
-                            }
+                        TokenType::Whitespace if !value_str.is_empty() => {
+                            value_str.push(' ');
                         }
                         TokenType::HexNumber(val) => {
                             write!(value_str, "${:X}", val).unwrap();
@@ -425,10 +423,8 @@ pub fn parse_token_line(tokens: &[Token], line_number: usize) -> Option<Assembly
                             args.push('"');
                         }
                         TokenType::Comma => args.push(','),
-                        TokenType::Whitespace => {
-                            if !args.is_empty() && !args.ends_with(',') {
-                                args.push(' ');
-                            }
+                        TokenType::Whitespace if !args.is_empty() && !args.ends_with(',') => {
+                            args.push(' ');
                         }
                         _ => {}
                     }
@@ -22,6 +22,12 @@
 
 use crate::{ExecutionError, MemoryBus, OPCODE_TABLE};
 
+/// Address of the NMI handler vector (low byte; high byte follows).
+pub(crate) const NMI_VECTOR: u16 = 0xFFFA;
+
+/// Address of the IRQ/BRK handler vector (low byte; high byte follows).
+pub(crate) const IRQ_VECTOR: u16 = 0xFFFE;
+
 /// 6502 CPU state and execution context.
 ///
 /// The CPU struct contains all processor state including registers, flags, program counter,
@@ -101,6 +107,23 @@ pub struct CPU<M: MemoryBus> {
     /// here for code clarity).
     pub(crate) irq_pending: bool,
 
+    /// NMI line level at the last instruction boundary
+    ///
+    /// NMI is edge-triggered: only a transition from inactive to active
+    /// latches a new NMI, so a device holding the line active does not
+    /// re-enter the handler after RTI.
+    pub(crate) nmi_line: bool,
+
+    /// NMI edge latched and waiting to be serviced
+    pub(crate) nmi_pending: bool,
+
+    /// Whether the D flag selects BCD arithmetic in ADC/SBC
+    ///
+    /// Enabled by default (NMOS 6502 behavior). When disabled the D flag can
+    /// still be set and cleared, but ADC/SBC always use binary arithmetic, as
+    /// on 6502 derivatives with the decimal circuitry removed.
+    pub(crate) decimal_mode_enabled: bool,
+
     /// Memory bus implementation
     pub(crate) memory: M,
 }
@@ -152,6 +175,9 @@ impl<M: MemoryBus> CPU<M> {
             flag_c: false,
             cycles: 0,
             irq_pending: false, // No interrupts pending on reset
+            nmi_line: false,
+            nmi_pending: false,
+            decimal_mode_enabled: true,
             memory,
         }
     }
@@ -210,6 +236,9 @@ impl<M: MemoryBus> CPU<M> {
             return Err(ExecutionError::UnimplementedOpcode(opcode));
         }
 
+        // I flag as seen by the IRQ poll (see `irq_poll_flag_i`)
+        let flag_i_before = self.flag_i;
+
         // Execute instruction based on mnemonic
         match metadata.mnemonic {
             "ADC" => {
@@ -380,6 +409,61 @@ impl<M: MemoryBus> CPU<M> {
             "TYA" => {
                 crate::instructions::transfer::execute_tya(self, opcode)?;
             }
+            // Illegal (undocumented) opcodes
+            "ALR" => {
+                crate::instructions::illegal::execute_alr(self, opcode)?;
+            }
+            "ANC" => {
+                crate::instructions::illegal::execute_anc(self, opcode)?;
+            }
+            "ARR" => {
+                crate::instructions::illegal::execute_arr(self, opcode)?;
+            }
+            "AXS" => {
+                crate::instructions::illegal::execute_axs(self, opcode)?;
+            }
+            "DCP" => {
+                crate::instructions::illegal::execute_dcp(self, opcode)?;
+            }
+            "ISC" => {
+                crate::instructions::illegal::execute_isc(self, opcode)?;
+            }
+            "LAS" => {
+                crate::instructions::illegal::execute_las(self, opcode)?;
+            }
+            "LAX" => {
+                crate::instructions::illegal::execute_lax(self, opcode)?;
+            }
+            "RLA" => {
+                crate::instructions::illegal::execute_rla(self, opcode)?;
+            }
+            "RRA" => {
+                crate::instructions::illegal::execute_rra(self, opcode)?;
+            }
+            "SAX" => {
+                crate::instructions::illegal::execute_sax(self, opcode)?;
+            }
+            "SHA" => {
+                crate::instructions::illegal::execute_sha(self, opcode)?;
+            }
+            "SHX" => {
+                crate::instructions::illegal::execute_shx(self, opcode)?;
+            }
+            "SHY" => {
+                crate::instructions::illegal::execute_shy(self, opcode)?;
+            }
+            "SLO" => {
+                crate::instructions::illegal::execute_slo(self, opcode)?;
+            }
+            "SRE" => {
+                crate::instructions::illegal::execute_sre(self, opcode)?;
+            }
+            "TAS" => {
+                crate::instructions::illegal::execute_tas(self, opcode)?;
+            }
+            "XAA" => {
+                crate::instructions::illegal::execute_xaa(self, opcode)?;
+            }
             _ => {
                 // Other instructions not yet implemented
                 self.cycles += metadata.base_cycles as u64;
@@ -389,11 +473,19 @@ impl<M: MemoryBus> CPU<M> {
         }
 
         // Check for interrupts at instruction boundary (after instruction completes)
+        self.check_nmi_line();
         self.check_irq_line();
 
-        // Service interrupt if IRQ line active and interrupts enabled
-        if self.should_service_interrupt() {
-            self.service_interrupt()?;
+        // NMI has priority and ignores the I flag; IRQ needs interrupts enabled.
+        // On hardware an NMI arriving during the IRQ sequence hijacks it; at
+        // instruction granularity that is the same as this priority, and the
+        // level-triggered IRQ is taken after the NMI handler returns.
+        let flag_i = Self::irq_poll_flag_i(metadata.mnemonic, flag_i_before, self.flag_i);
+        if self.nmi_pending {
+            self.nmi_pending = false;
+            self.service_interrupt(NMI_VECTOR)?;
+        } else if self.should_service_interrupt(flag_i) {
+            self.service_interrupt(IRQ_VECTOR)?;
         }
 
         Ok(())
@@ -460,12 +552,80 @@ impl<M: MemoryBus> CPU<M> {
         self.irq_pending = self.memory.irq_active();
     }
 
+    /// Sample the NMI line and latch a pending NMI on an inactive-to-active edge.
+    ///
+    /// Called after each instruction execution in `step()`, before the IRQ
+    /// check, and by BRK before its vector fetch.
+    pub(crate) fn check_nmi_line(&mut self) {
+        let line = self.memory.nmi_active();
+        if line && !self.nmi_line {
+            self.nmi_pending = true;
+        }
+        self.nmi_line = line;
+    }
+
+    /// Latch a non-maskable interrupt, as if the NMI line had just become active.
+    ///
+    /// Use this for NMI sources that are not memory-mapped devices, such as a
+    /// front-panel button or the C64 RESTORE key. The NMI is serviced at the
+    /// end of the next instruction regardless of the I flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib6502::{CPU, FlatMemory, MemoryBus};
+    ///
+    /// let mut mem = FlatMemory::new();
+    /// mem.write(0xFFFC, 0x00);
+    /// mem.write(0xFFFD, 0x80);
+    /// mem.write(0xFFFA, 0x00); // NMI vector -> $9000
+    /// mem.write(0xFFFB, 0x90);
+    /// mem.write(0x8000, 0xEA); // NOP
+    ///
+    /// let mut cpu = CPU::new(mem);
+    /// cpu.trigger_nmi();
+    /// assert!(cpu.nmi_pending());
+    ///
+    /// cpu.step().unwrap();
+    /// assert_eq!(cpu.pc(), 0x9000);
+    /// assert!(!cpu.nmi_pending());
+    /// ```
+    pub fn trigger_nmi(&mut self) {
+        self.nmi_pending = true;
+    }
+
+    /// Returns true if an NMI edge has been latched but not yet serviced.
+    pub fn nmi_pending(&self) -> bool {
+        self.nmi_pending
+    }
+
+    /// Returns the I flag value the IRQ poll sees at the end of an instruction.
+    ///
+    /// The 6502 polls for interrupts before the last cycle of an instruction.
+    /// CLI, SEI and PLP change the I flag during that last cycle, so the poll
+    /// still sees the old value:
+    /// - After CLI (or PLP clearing I) one more instruction runs before a
+    ///   pending IRQ is taken
+    /// - After SEI (or PLP setting I) a pending IRQ is still taken, with I
+    ///   already set in the pushed status
+    ///
+    /// RTI restores I before the poll, so its effect is immediate.
+    fn irq_poll_flag_i(mnemonic: &str, flag_i_before: bool, flag_i_after: bool) -> bool {
+        match mnemonic {
+            "CLI" | "SEI" | "PLP" => flag_i_before,
+            _ => flag_i_after,
+        }
+    }
+
     /// Determine if CPU should service an interrupt request.
     ///
     /// Returns `true` if all conditions for interrupt servicing are met:
     /// - IRQ line is active (at least one device has pending interrupt)
     /// - I flag is clear (interrupts are enabled)
     ///
+    /// `flag_i` is the I flag as seen by the IRQ poll, which lags behind the
+    /// real flag after CLI, SEI and PLP (see `irq_poll_flag_i`).
+    ///
     /// # Hardware Behavior
     ///
     /// Real 6502 hardware checks the IRQ line at the end of every instruction.
@@ -476,8 +636,8 @@ impl<M: MemoryBus> CPU<M> {
     ///
     /// - `true` if interrupt should be serviced immediately
     /// - `false` if no interrupt or interrupts are disabled
-    fn should_service_interrupt(&self) -> bool {
-        self.irq_pending && !self.flag_i
+    fn should_service_interrupt(&self, flag_i: bool) -> bool {
+        self.irq_pending && !flag_i
     }
 
     /// Add cycles to the cycle counter.
@@ -508,19 +668,22 @@ impl<M: MemoryBus> CPU<M> {
         self.tick(1);
     }
 
-    /// Service a pending interrupt request with cycle-accurate 6502 IRQ sequence.
+    /// Service a pending interrupt with the cycle-accurate 6502 IRQ/NMI sequence.
     ///
     /// Implements the 7-cycle hardware interrupt sequence:
     /// 1. Push PC high byte to stack (1 cycle)
     /// 2. Push PC low byte to stack (1 cycle)
     /// 3. Push status register to stack (1 cycle)
     /// 4. Set I flag to prevent nested interrupts (0 cycles, part of step 3)
-    /// 5. Read IRQ vector low byte from 0xFFFE (1 cycle)
-    /// 6. Read IRQ vector high byte from 0xFFFF (1 cycle)
+    /// 5. Read vector low byte (1 cycle)
+    /// 6. Read vector high byte (1 cycle)
     /// 7. Set PC to vector address (2 cycles, internal operation)
     ///
     /// **Total: 7 cycles** (matches MOS 6502 specification)
     ///
+    /// IRQ and NMI differ only in the vector: `IRQ_VECTOR` ($FFFE) or
+    /// `NMI_VECTOR` ($FFFA).
+    ///
     /// # Stack Layout After Service
     ///
     /// ```text
@@ -533,25 +696,25 @@ impl<M: MemoryBus> CPU<M> {
     ///
     /// Always returns `Ok(())` - the 6502 has no interrupt failure modes.
     /// Stack overflow is not checked (matches hardware behavior).
-    fn service_interrupt(&mut self) -> Result<(), ExecutionError> {
+    fn service_interrupt(&mut self, vector: u16) -> Result<(), ExecutionError> {
         // Cycle 1-2: Push PC high byte, then low byte to stack
         let [pc_high, pc_low] = self.pc.to_be_bytes();
         self.push_stack_timed(pc_high);
         self.push_stack_timed(pc_low);
 
         // Cycle 3: Push status register to stack
-        // Note: B flag is pushed as 0 for IRQ (differs from BRK which pushes 1)
-        let status = self.status() & !0b00010000; // Clear B flag for IRQ
+        // Note: B flag is pushed as 0 for IRQ/NMI (differs from BRK which pushes 1)
+        let status = self.status() & !0b00010000; // Clear B flag for IRQ/NMI
         self.push_stack_timed(status);
 
         // Set I flag to prevent nested interrupts (0 cycles, part of above operation)
         self.flag_i = true;
 
-        // Cycle 4-5: Read IRQ vector from 0xFFFE-0xFFFF
-        let vector_low = self.memory.read(0xFFFE) as u16;
+        // Cycle 4-5: Read handler address from the vector
+        let vector_low = self.memory.read(vector) as u16;
         self.tick(1);
 
-        let vector_high = self.memory.read(0xFFFF) as u16;
+        let vector_high = self.memory.read(vector.wrapping_add(1)) as u16;
         self.tick(1);
 
         // Cycle 6-7: Set PC to vector address (2 cycles for internal operation)
@@ -816,6 +979,65 @@ impl<M: MemoryBus> CPU<M> {
         self.sp = value;
     }
 
+    /// Returns true if ADC/SBC honor the Decimal flag.
+    ///
+    /// See [`CPU::set_decimal_mode_enabled`].
+    pub fn decimal_mode_enabled(&self) -> bool {
+        self.decimal_mode_enabled
+    }
+
+    /// Enables or disables BCD arithmetic support.
+    ///
+    /// Decimal mode is enabled by default, matching the NMOS 6502. Disabling
+    /// it makes ADC/SBC ignore the D flag and always compute in binary, which
+    /// is useful for testing software against 6502 variants without decimal
+    /// mode. The D flag itself still behaves normally.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib6502::{CPU, FlatMemory, MemoryBus};
+    ///
+    /// let mut mem = FlatMemory::new();
+    /// mem.write(0xFFFC, 0x00);
+    /// mem.write(0xFFFD, 0x80);
+    /// mem.write(0x8000, 0x69); // ADC #$01
+    /// mem.write(0x8001, 0x01);
+    ///
+    /// let mut cpu = CPU::new(mem);
+    /// cpu.set_decimal_mode_enabled(false);
+    /// cpu.set_flag_d(true);
+    /// cpu.set_a(0x09);
+    ///
+    /// cpu.step().unwrap();
+    /// assert_eq!(cpu.a(), 0x0A); // Binary result despite D flag
+    /// ```
+    pub fn set_decimal_mode_enabled(&mut self, enabled: bool) {
+        self.decimal_mode_enabled = enabled;
+    }
+
+    /// Returns a shared reference to the memory bus.
+    ///
+    /// This allows debuggers and other tools to inspect memory without
+    /// needing mutable access to the CPU.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib6502::{CPU, FlatMemory, MemoryBus};
+    ///
+    /// let mut mem = FlatMemory::new();
+    /// mem.write(0xFFFC, 0x00);
+    /// mem.write(0xFFFD, 0x80);
+    /// mem.write(0x8000, 0xEA);
+    ///
+    /// let cpu = CPU::new(mem);
+    /// assert_eq!(cpu.memory().read(0x8000), 0xEA);
+    /// ```
+    pub fn memory(&self) -> &M {
+        &self.memory
+    }
+
     /// Returns a mutable reference to the memory bus.
     ///
     /// This allows tests and external code to write to memory.
@@ -1098,14 +1320,14 @@ mod tests {
         let mut mem = FlatMemory::new();
         mem.write(0xFFFC, 0x00);
         mem.write(0xFFFD, 0x80);
-        mem.write(0x8000, 0x02); // Illegal/undocumented opcode (not implemented)
+        mem.write(0x8000, 0x02); // JAM opcode (not implemented)
 
         let mut cpu = CPU::new(mem);
 
         match cpu.step() {
             Err(ExecutionError::UnimplementedOpcode(0x02)) => {
                 // Expected error
-                assert_eq!(cpu.pc(), 0x8001); // PC advanced by instruction size (1 byte for illegal opcodes)
+                assert_eq!(cpu.pc(), 0x8001); // PC advanced by instruction size (1 byte for JAM opcodes)
             }
             _ => panic!("Expected UnimplementedOpcode error"),
         }
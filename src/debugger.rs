@@ -0,0 +1,23 @@
+//! 6502 Debugging Utilities
+//!
+//! Tools for inspecting a running CPU from the outside without changing how it
+//! executes. Everything here is generic over `MemoryBus`, so it can be used
+//! with any memory implementation.
+
+pub mod call_stack;
+pub mod gdb;
+pub mod memory_scan;
+pub mod monitor;
+pub mod reverse;
+pub mod trace;
+pub mod watchdog;
+
+pub use call_stack::{
+    walk_stack, ActiveCall, CallEvent, CallKind, CallTracer, StackFrame, SubroutineStats,
+};
+pub use gdb::GdbStub;
+pub use memory_scan::{refine, scan, BytePattern, MemoryScan};
+pub use monitor::Monitor;
+pub use reverse::{JournaledMemory, ReverseStepper};
+pub use trace::{TraceEntry, Tracer};
+pub use watchdog::{HangReason, HangReport, Watchdog};
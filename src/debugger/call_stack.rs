@@ -0,0 +1,412 @@
+//! Call stack inspection and subroutine call tracing.
+//!
+//! Two complementary views of "how did we get here":
+//!
+//! - [`walk_stack`] scans the hardware stack page and heuristically picks out
+//!   JSR return addresses. It needs no history, so it works on a CPU that was
+//!   stopped at any point.
+//! - [`CallTracer`] wraps `CPU::step()` and keeps an exact shadow call stack by
+//!   watching JSR/RTS, BRK/RTI and hardware interrupts as they execute. It also
+//!   emits call/return events and accumulates per-subroutine cycle totals for
+//!   simple profiling.
+
+use crate::{ExecutionError, MemoryBus, CPU, OPCODE_TABLE};
+use std::collections::BTreeMap;
+
+/// Opcode byte for JSR absolute.
+const JSR_OPCODE: u8 = 0x20;
+
+/// Cycles consumed by the hardware interrupt entry sequence.
+const INTERRUPT_CYCLES: u64 = 7;
+
+/// A JSR return address found on the hardware stack by [`walk_stack`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StackFrame {
+    /// Stack address holding the low byte of the pushed return address
+    pub stack_addr: u16,
+
+    /// Address of the JSR instruction that pushed this frame
+    pub call_site: u16,
+
+    /// Subroutine entry point (the JSR operand)
+    pub target: u16,
+
+    /// Address execution resumes at after the matching RTS
+    pub return_addr: u16,
+}
+
+/// Reconstruct the JSR call chain from the hardware stack.
+///
+/// Scans the stack from the current stack pointer up to $01FF looking for
+/// 16-bit values that point at the last byte of a JSR instruction, which is
+/// exactly what JSR pushes. Bytes that don't match are treated as data (saved
+/// registers, interrupt status bytes, etc.) and skipped.
+///
+/// Frames are returned innermost first. Because this is a heuristic, a data
+/// byte pair that happens to point just past a `$20` byte will be reported as a
+/// frame; use [`CallTracer`] when an exact history is required.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::{CPU, FlatMemory, MemoryBus};
+/// use lib6502::debugger::walk_stack;
+///
+/// let mut mem = FlatMemory::new();
+/// mem.write(0xFFFC, 0x00);
+/// mem.write(0xFFFD, 0x80);
+///
+/// // $8000: JSR $9000
+/// mem.write(0x8000, 0x20);
+/// mem.write(0x8001, 0x00);
+/// mem.write(0x8002, 0x90);
+///
+/// let mut cpu = CPU::new(mem);
+/// cpu.step().unwrap();
+///
+/// let frames = walk_stack(&cpu);
+/// assert_eq!(frames.len(), 1);
+/// assert_eq!(frames[0].call_site, 0x8000);
+/// assert_eq!(frames[0].target, 0x9000);
+/// assert_eq!(frames[0].return_addr, 0x8003);
+/// ```
+pub fn walk_stack<M: MemoryBus>(cpu: &CPU<M>) -> Vec<StackFrame> {
+    let memory = cpu.memory();
+    let mut frames = Vec::new();
+
+    // Need two bytes (low, high) per candidate, so stop one short of $01FF
+    let mut offset = cpu.sp() as u16 + 1;
+    while offset < 0xFF {
+        let stack_addr = 0x0100 | offset;
+        let lo = memory.peek(stack_addr) as u16;
+        let hi = memory.peek(stack_addr + 1) as u16;
+        let pushed = (hi << 8) | lo;
+
+        // JSR pushes the address of its own last byte (call site + 2)
+        let call_site = pushed.wrapping_sub(2);
+        if memory.peek(call_site) == JSR_OPCODE {
+            let target_lo = memory.peek(call_site.wrapping_add(1)) as u16;
+            let target_hi = memory.peek(call_site.wrapping_add(2)) as u16;
+
+            frames.push(StackFrame {
+                stack_addr,
+                call_site,
+                target: (target_hi << 8) | target_lo,
+                return_addr: pushed.wrapping_add(1),
+            });
+            offset += 2;
+        } else {
+            offset += 1;
+        }
+    }
+
+    frames
+}
+
+/// How a traced call was entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallKind {
+    /// JSR, returned from with RTS
+    Subroutine,
+
+    /// BRK or hardware interrupt, returned from with RTI
+    Interrupt,
+}
+
+/// A call currently in progress on the [`CallTracer`] shadow stack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActiveCall {
+    /// How the call was entered
+    pub kind: CallKind,
+
+    /// Address of the JSR/BRK, or of the interrupted instruction
+    pub call_site: u16,
+
+    /// Subroutine or handler entry point
+    pub target: u16,
+
+    /// CPU cycle count when the call started
+    pub start_cycle: u64,
+
+    /// Stack pointer just after the return address (and status) were pushed.
+    ///
+    /// The matching RTS or RTI executes with SP at this value.
+    pub stack_pointer: u8,
+}
+
+impl ActiveCall {
+    /// Returns true if a return executed with stack pointer `sp`, about to
+    /// pop `popped`, is the return from this call.
+    ///
+    /// JSR and BRK push the address of their last byte plus one (call site
+    /// + 2); a hardware interrupt pushes the interrupted instruction itself.
+    fn returns_here(&self, sp: u8, popped: u16) -> bool {
+        let pushed = self.call_site.wrapping_add(2);
+        sp == self.stack_pointer
+            || popped == pushed
+            || (self.kind == CallKind::Interrupt && popped == self.call_site)
+    }
+}
+
+/// A call or return observed by [`CallTracer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallEvent {
+    /// A subroutine or interrupt handler was entered
+    Call {
+        /// How the call was entered
+        kind: CallKind,
+        /// Address of the JSR/BRK, or of the interrupted instruction
+        call_site: u16,
+        /// Subroutine or handler entry point
+        target: u16,
+        /// CPU cycle count when the call started
+        cycle: u64,
+    },
+
+    /// A subroutine or interrupt handler returned
+    Return {
+        /// How the returning call was entered
+        kind: CallKind,
+        /// Entry point of the call that returned
+        target: u16,
+        /// CPU cycle count after the RTS/RTI completed
+        cycle: u64,
+        /// Cycles spent inside the call, including nested calls
+        elapsed: u64,
+    },
+}
+
+/// Accumulated profile for one subroutine or handler entry point.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubroutineStats {
+    /// Number of completed calls
+    pub calls: u64,
+
+    /// Total cycles spent in completed calls, including nested calls
+    pub cycles: u64,
+}
+
+/// Traces subroutine calls and returns while stepping the CPU.
+///
+/// Use [`CallTracer::step`] in place of `CPU::step()`. After each step the
+/// tracer's shadow call stack matches what the program has actually called,
+/// and any calls or returns are queued as [`CallEvent`]s until taken.
+///
+/// A return matches an active call when it executes with the stack pointer
+/// the call left behind, or pops the return address the call pushed. Returns
+/// that don't match any active call (stack tricks such as pushing a fake
+/// return address and executing RTS) are ignored. A return that matches an
+/// outer call discards any inner calls that were abandoned.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::{CPU, FlatMemory, MemoryBus};
+/// use lib6502::debugger::{CallEvent, CallTracer};
+///
+/// let mut mem = FlatMemory::new();
+/// mem.write(0xFFFC, 0x00);
+/// mem.write(0xFFFD, 0x80);
+///
+/// // $8000: JSR $9000
+/// mem.write(0x8000, 0x20);
+/// mem.write(0x8001, 0x00);
+/// mem.write(0x8002, 0x90);
+/// // $9000: RTS
+/// mem.write(0x9000, 0x60);
+///
+/// let mut cpu = CPU::new(mem);
+/// let mut tracer = CallTracer::new();
+///
+/// tracer.step(&mut cpu).unwrap(); // JSR
+/// assert_eq!(tracer.call_stack().len(), 1);
+///
+/// tracer.step(&mut cpu).unwrap(); // RTS
+/// assert!(tracer.call_stack().is_empty());
+///
+/// let events = tracer.take_events();
+/// assert!(matches!(events[1], CallEvent::Return { target: 0x9000, elapsed: 12, .. }));
+/// assert_eq!(tracer.profile()[&0x9000].calls, 1);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CallTracer {
+    frames: Vec<ActiveCall>,
+    events: Vec<CallEvent>,
+    profile: BTreeMap<u16, SubroutineStats>,
+}
+
+impl CallTracer {
+    /// Create a tracer with an empty shadow stack and profile.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Execute one instruction and record any calls or returns it performs.
+    ///
+    /// Also detects a hardware interrupt serviced at the end of the step.
+    ///
+    /// # Returns
+    ///
+    /// The result of the underlying `CPU::step()`. Nothing is recorded when
+    /// the step fails.
+    pub fn step<M: MemoryBus>(&mut self, cpu: &mut CPU<M>) -> Result<(), ExecutionError> {
+        let pc = cpu.pc();
+        let sp = cpu.sp();
+        let x = cpu.x();
+        let start_cycle = cpu.cycles();
+        let opcode = cpu.memory().peek(pc);
+        let mnemonic = OPCODE_TABLE[opcode as usize].mnemonic;
+
+        // The undocumented TAS and LAS also load SP, from A & X and from
+        // memory & SP
+        let loaded_sp = match mnemonic {
+            "TAS" => cpu.a() & x,
+            "LAS" => {
+                let memory = cpu.memory();
+                let lo = memory.peek(pc.wrapping_add(1)) as u16;
+                let hi = memory.peek(pc.wrapping_add(2)) as u16;
+                let addr = ((hi << 8) | lo).wrapping_add(cpu.y() as u16);
+                memory.peek(addr) & sp
+            }
+            _ => x,
+        };
+
+        // Return address an RTS or RTI is about to pull from the stack
+        let popped = match mnemonic {
+            "RTS" => Self::stack_word(cpu, sp.wrapping_add(1)),
+            "RTI" => Self::stack_word(cpu, sp.wrapping_add(2)),
+            _ => 0,
+        };
+
+        cpu.step()?;
+
+        // Stack pointer the instruction alone would leave behind. Anything three
+        // bytes lower means the CPU also pushed an interrupt frame.
+        let expected_sp = match mnemonic {
+            "JSR" => sp.wrapping_sub(2),
+            "RTS" => sp.wrapping_add(2),
+            "BRK" => sp.wrapping_sub(3),
+            "RTI" => sp.wrapping_add(3),
+            "PHA" | "PHP" => sp.wrapping_sub(1),
+            "PLA" | "PLP" => sp.wrapping_add(1),
+            "TXS" | "TAS" | "LAS" => loaded_sp,
+            _ => sp,
+        };
+        let interrupted = cpu.sp() == expected_sp.wrapping_sub(3);
+        let end_cycle = if interrupted {
+            cpu.cycles() - INTERRUPT_CYCLES
+        } else {
+            cpu.cycles()
+        };
+
+        match mnemonic {
+            "JSR" => {
+                let memory = cpu.memory();
+                let lo = memory.peek(pc.wrapping_add(1)) as u16;
+                let hi = memory.peek(pc.wrapping_add(2)) as u16;
+                let target = (hi << 8) | lo;
+                self.enter(CallKind::Subroutine, pc, target, expected_sp, start_cycle);
+            }
+            // BRK sets I, so no IRQ can be serviced in the same step and the
+            // new PC is the handler entry point
+            "BRK" => self.enter(CallKind::Interrupt, pc, cpu.pc(), expected_sp, start_cycle),
+            "RTS" => self.leave(CallKind::Subroutine, sp, popped, end_cycle),
+            "RTI" => self.leave(CallKind::Interrupt, sp, popped, end_cycle),
+            _ => {}
+        }
+
+        if interrupted {
+            let call_site = Self::pushed_return_address(cpu);
+            self.enter(
+                CallKind::Interrupt,
+                call_site,
+                cpu.pc(),
+                cpu.sp(),
+                end_cycle,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Returns the shadow call stack, outermost call first.
+    pub fn call_stack(&self) -> &[ActiveCall] {
+        &self.frames
+    }
+
+    /// Removes and returns all queued call/return events, oldest first.
+    pub fn take_events(&mut self) -> Vec<CallEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    /// Returns accumulated statistics keyed by subroutine entry point.
+    pub fn profile(&self) -> &BTreeMap<u16, SubroutineStats> {
+        &self.profile
+    }
+
+    /// Forget the shadow stack, queued events, and profile.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+        self.events.clear();
+        self.profile.clear();
+    }
+
+    /// Push a new call onto the shadow stack and queue its event.
+    fn enter(&mut self, kind: CallKind, call_site: u16, target: u16, sp: u8, cycle: u64) {
+        self.frames.push(ActiveCall {
+            kind,
+            call_site,
+            target,
+            start_cycle: cycle,
+            stack_pointer: sp,
+        });
+        self.events.push(CallEvent::Call {
+            kind,
+            call_site,
+            target,
+            cycle,
+        });
+    }
+
+    /// Pop the innermost call of `kind` that a return executed with stack
+    /// pointer `sp` and about to pop `popped` belongs to, and record its
+    /// duration.
+    fn leave(&mut self, kind: CallKind, sp: u8, popped: u16, cycle: u64) {
+        let Some(index) = self
+            .frames
+            .iter()
+            .rposition(|frame| frame.kind == kind && frame.returns_here(sp, popped))
+        else {
+            return;
+        };
+
+        let call = self.frames[index];
+        self.frames.truncate(index);
+
+        let elapsed = cycle - call.start_cycle;
+        let stats = self.profile.entry(call.target).or_default();
+        stats.calls += 1;
+        stats.cycles += elapsed;
+
+        self.events.push(CallEvent::Return {
+            kind,
+            target: call.target,
+            cycle,
+            elapsed,
+        });
+    }
+
+    /// Read the return address from the interrupt frame at the top of the stack.
+    ///
+    /// Layout after an interrupt: SP+1 = status, SP+2 = PC low, SP+3 = PC high.
+    fn pushed_return_address<M: MemoryBus>(cpu: &CPU<M>) -> u16 {
+        Self::stack_word(cpu, cpu.sp().wrapping_add(2))
+    }
+
+    /// Read the little-endian word at `offset` in the stack page.
+    fn stack_word<M: MemoryBus>(cpu: &CPU<M>, offset: u8) -> u16 {
+        let lo = cpu.memory().peek(0x0100 | offset as u16) as u16;
+        let hi = cpu.memory().peek(0x0100 | offset.wrapping_add(1) as u16) as u16;
+        (hi << 8) | lo
+    }
+}
@@ -0,0 +1,450 @@
+//! GDB Remote Serial Protocol stub.
+//!
+//! [`GdbStub`] implements the subset of the GDB RSP needed to attach a
+//! debugger front end: registers, memory, software breakpoints, stepping and
+//! continuing. It owns no transport; the embedder passes received bytes to
+//! [`GdbStub::feed`] and sends back whatever it returns, so the same stub
+//! works over TCP natively or a WebSocket in the browser.
+//!
+//! Continuing does not block. `c` only marks the target as running; the
+//! embedder then calls [`GdbStub::run`] with an instruction budget (for
+//! example once per frame) until it returns a stop reply. A `0x03` byte from
+//! the client interrupts a running target.
+//!
+//! # Register Layout
+//!
+//! GDB has no built-in 6502 target, so registers are numbered as follows and
+//! sent little-endian in `g`/`G`/`p`/`P` packets:
+//!
+//! | Number | Register | Size    |
+//! |--------|----------|---------|
+//! | 0      | A        | 1 byte  |
+//! | 1      | X        | 1 byte  |
+//! | 2      | Y        | 1 byte  |
+//! | 3      | P        | 1 byte  |
+//! | 4      | SP       | 1 byte  |
+//! | 5      | PC       | 2 bytes |
+
+use crate::{MemoryBus, CPU};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// Stop signal for breakpoints and completed steps.
+const SIGTRAP: u8 = 5;
+
+/// Stop signal when the client interrupts a running target.
+const SIGINT: u8 = 2;
+
+/// Stop signal when the CPU hits an unimplemented opcode.
+const SIGILL: u8 = 4;
+
+/// Interrupt request byte sent by GDB outside of a packet (Ctrl-C).
+const INTERRUPT: u8 = 0x03;
+
+/// Largest packet we accept, advertised in `qSupported`.
+const PACKET_SIZE: usize = 0x1000;
+
+/// Register sizes in bytes, indexed by GDB register number.
+const REGISTER_SIZES: [usize; 6] = [1, 1, 1, 1, 1, 2];
+
+/// Transport-agnostic GDB remote protocol server for a [`CPU`].
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::{CPU, FlatMemory, MemoryBus};
+/// use lib6502::debugger::GdbStub;
+///
+/// let mut mem = FlatMemory::new();
+/// mem.write(0xFFFC, 0x00);
+/// mem.write(0xFFFD, 0x80);
+/// mem.write(0x8000, 0xA9); // LDA #$42
+/// mem.write(0x8001, 0x42);
+///
+/// let mut cpu = CPU::new(mem);
+/// let mut stub = GdbStub::new();
+///
+/// // "$s#73" asks for a single step; the reply is an ack and a stop packet
+/// let reply = stub.feed(&mut cpu, b"$s#73");
+/// assert_eq!(reply, b"+$S05#b8");
+/// assert_eq!(cpu.a(), 0x42);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GdbStub {
+    input: Vec<u8>,
+    breakpoints: BTreeSet<u16>,
+    running: bool,
+    no_ack: bool,
+    /// Signal of the most recent stop, reported again by `?`
+    last_stop: Option<u8>,
+}
+
+impl GdbStub {
+    /// Create a stub with no breakpoints, with the target stopped.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true while the target is continuing after a `c` packet.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Returns the breakpoint addresses in ascending order.
+    pub fn breakpoints(&self) -> impl Iterator<Item = u16> + '_ {
+        self.breakpoints.iter().copied()
+    }
+
+    /// Process bytes received from the client and return the bytes to send back.
+    ///
+    /// Partial packets are buffered until the rest arrives.
+    pub fn feed<M: MemoryBus>(&mut self, cpu: &mut CPU<M>, bytes: &[u8]) -> Vec<u8> {
+        self.input.extend_from_slice(bytes);
+        let mut output = Vec::new();
+
+        // Consume the buffer through a cursor and drain it once at the end, so
+        // a large feed costs time linear in its length
+        let mut pos = 0;
+        loop {
+            // Outside a packet: acks are ignored, 0x03 interrupts the target
+            while let Some(&byte) = self.input.get(pos) {
+                if byte == b'$' {
+                    break;
+                }
+                if byte == INTERRUPT && self.running {
+                    let reply = self.stop(SIGINT);
+                    self.send(&mut output, &reply);
+                }
+                pos += 1;
+            }
+
+            let Some(end) = self.input[pos..].iter().position(|&b| b == b'#') else {
+                break;
+            };
+            let end = pos + end;
+            if self.input.len() < end + 3 {
+                break;
+            }
+
+            let body = self.input[pos + 1..end].to_vec();
+            let expected = std::str::from_utf8(&self.input[end + 1..end + 3])
+                .ok()
+                .and_then(parse_hex::<u8>);
+            pos = end + 3;
+
+            if expected != Some(checksum(&body)) {
+                if !self.no_ack {
+                    output.push(b'-');
+                }
+                continue;
+            }
+            if !self.no_ack {
+                output.push(b'+');
+            }
+
+            let body = String::from_utf8_lossy(&body).into_owned();
+            if let Some(reply) = self.handle_packet(cpu, &body) {
+                self.send(&mut output, &reply);
+            }
+        }
+
+        self.input.drain(..pos);
+        output
+    }
+
+    /// Execute up to `max_instructions` while the target is running.
+    ///
+    /// Returns the stop reply to send when the target stops at a breakpoint or
+    /// on an error, and an empty vector if it is still running (or was not
+    /// running at all).
+    pub fn run<M: MemoryBus>(&mut self, cpu: &mut CPU<M>, max_instructions: u64) -> Vec<u8> {
+        let mut output = Vec::new();
+        if !self.running {
+            return output;
+        }
+
+        for _ in 0..max_instructions {
+            let signal = match cpu.step() {
+                Err(_) => SIGILL,
+                Ok(()) if self.breakpoints.contains(&cpu.pc()) => SIGTRAP,
+                Ok(()) => continue,
+            };
+            let reply = self.stop(signal);
+            self.send(&mut output, &reply);
+            break;
+        }
+
+        output
+    }
+
+    /// Handle one packet body, returning the reply or `None` for no reply.
+    fn handle_packet<M: MemoryBus>(&mut self, cpu: &mut CPU<M>, packet: &str) -> Option<String> {
+        let (command, args) = packet.split_at(packet.chars().next().map_or(0, char::len_utf8));
+
+        let reply = match command {
+            "?" => stop_reply(self.last_stop.unwrap_or(SIGTRAP)),
+            "g" => read_registers(cpu),
+            "G" => write_registers(cpu, args),
+            "p" => read_register(cpu, args),
+            "P" => write_register(cpu, args),
+            "m" => read_memory(cpu, args),
+            "M" => write_memory(cpu, args),
+            "Z" | "z" => self.breakpoint(command == "Z", args),
+            "s" => {
+                match parse_optional_address(args) {
+                    Ok(Some(addr)) => cpu.set_pc(addr),
+                    Ok(None) => {}
+                    Err(()) => return Some(error_reply()),
+                }
+                match cpu.step() {
+                    Ok(()) => self.stop(SIGTRAP),
+                    Err(_) => self.stop(SIGILL),
+                }
+            }
+            "c" => {
+                match parse_optional_address(args) {
+                    Ok(Some(addr)) => cpu.set_pc(addr),
+                    Ok(None) => {}
+                    Err(()) => return Some(error_reply()),
+                }
+                self.running = true;
+                return None;
+            }
+            "H" => "OK".to_string(),
+            "D" => {
+                self.breakpoints.clear();
+                self.running = false;
+                "OK".to_string()
+            }
+            "k" => {
+                self.running = false;
+                return None;
+            }
+            "q" | "Q" => self.query(packet),
+            _ => String::new(),
+        };
+
+        Some(reply)
+    }
+
+    /// Handle general query and set packets (`q...`/`Q...`).
+    fn query(&mut self, packet: &str) -> String {
+        let name = packet.split([':', ',']).next().unwrap_or(packet);
+        match name {
+            "qSupported" => format!("PacketSize={:x};QStartNoAckMode+", PACKET_SIZE),
+            "qAttached" => "1".to_string(),
+            "qC" => "QC1".to_string(),
+            "qfThreadInfo" => "m1".to_string(),
+            "qsThreadInfo" => "l".to_string(),
+            "QStartNoAckMode" => {
+                self.no_ack = true;
+                "OK".to_string()
+            }
+            _ => String::new(),
+        }
+    }
+
+    /// Handle `Z`/`z` packets. Software and hardware breakpoints are treated alike.
+    fn breakpoint(&mut self, insert: bool, args: &str) -> String {
+        let mut fields = args.split(',');
+        let (Some(kind), Some(addr)) = (fields.next(), fields.next()) else {
+            return error_reply();
+        };
+        if kind != "0" && kind != "1" {
+            return String::new(); // Watchpoints unsupported
+        }
+        let Some(addr) = parse_hex(addr) else {
+            return error_reply();
+        };
+
+        if insert {
+            self.breakpoints.insert(addr);
+        } else {
+            self.breakpoints.remove(&addr);
+        }
+        "OK".to_string()
+    }
+
+    /// Stop the target with `signal` and return the stop reply.
+    fn stop(&mut self, signal: u8) -> String {
+        self.running = false;
+        self.last_stop = Some(signal);
+        stop_reply(signal)
+    }
+
+    /// Frame `payload` as `$payload#checksum` and append it to `output`.
+    fn send(&self, output: &mut Vec<u8>, payload: &str) {
+        output.push(b'$');
+        output.extend_from_slice(payload.as_bytes());
+        output.push(b'#');
+        output.extend_from_slice(format!("{:02x}", checksum(payload.as_bytes())).as_bytes());
+    }
+}
+
+// ========== Packet Helpers ==========
+
+fn checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |sum, &b| sum.wrapping_add(b))
+}
+
+fn stop_reply(signal: u8) -> String {
+    format!("S{:02x}", signal)
+}
+
+fn error_reply() -> String {
+    "E01".to_string()
+}
+
+/// Parse the optional resume address of `s`/`c` packets.
+fn parse_optional_address(args: &str) -> Result<Option<u16>, ()> {
+    if args.is_empty() {
+        return Ok(None);
+    }
+    parse_hex(args).map(Some).ok_or(())
+}
+
+/// Parse a hex number field. Unlike `from_str_radix` alone, this rejects
+/// anything but hex digits, such as a leading `+`.
+fn parse_hex<T: TryFrom<u64>>(text: &str) -> Option<T> {
+    if text.is_empty() || !text.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    u64::from_str_radix(text, 16).ok()?.try_into().ok()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| parse_hex(hex.get(i..i + 2)?))
+        .collect()
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, b| {
+        let _ = write!(hex, "{:02x}", b);
+        hex
+    })
+}
+
+// ========== Registers ==========
+
+fn register_bytes<M: MemoryBus>(cpu: &CPU<M>, number: usize) -> Vec<u8> {
+    match number {
+        0 => vec![cpu.a()],
+        1 => vec![cpu.x()],
+        2 => vec![cpu.y()],
+        3 => vec![cpu.status()],
+        4 => vec![cpu.sp()],
+        _ => cpu.pc().to_le_bytes().to_vec(),
+    }
+}
+
+fn set_register<M: MemoryBus>(cpu: &mut CPU<M>, number: usize, bytes: &[u8]) {
+    match number {
+        0 => cpu.set_a(bytes[0]),
+        1 => cpu.set_x(bytes[0]),
+        2 => cpu.set_y(bytes[0]),
+        3 => {
+            let status = bytes[0];
+            cpu.set_flag_n(status & 0x80 != 0);
+            cpu.set_flag_v(status & 0x40 != 0);
+            cpu.set_flag_b(status & 0x10 != 0);
+            cpu.set_flag_d(status & 0x08 != 0);
+            cpu.set_flag_i(status & 0x04 != 0);
+            cpu.set_flag_z(status & 0x02 != 0);
+            cpu.set_flag_c(status & 0x01 != 0);
+        }
+        4 => cpu.set_sp(bytes[0]),
+        _ => cpu.set_pc(u16::from_le_bytes([bytes[0], bytes[1]])),
+    }
+}
+
+fn read_registers<M: MemoryBus>(cpu: &CPU<M>) -> String {
+    let bytes: Vec<u8> = (0..REGISTER_SIZES.len())
+        .flat_map(|number| register_bytes(cpu, number))
+        .collect();
+    encode_hex(&bytes)
+}
+
+fn write_registers<M: MemoryBus>(cpu: &mut CPU<M>, args: &str) -> String {
+    let Some(bytes) = decode_hex(args) else {
+        return error_reply();
+    };
+    if bytes.len() != REGISTER_SIZES.iter().sum::<usize>() {
+        return error_reply();
+    }
+
+    let mut offset = 0;
+    for (number, &size) in REGISTER_SIZES.iter().enumerate() {
+        set_register(cpu, number, &bytes[offset..offset + size]);
+        offset += size;
+    }
+    "OK".to_string()
+}
+
+fn read_register<M: MemoryBus>(cpu: &CPU<M>, args: &str) -> String {
+    match parse_hex::<usize>(args) {
+        Some(number) if number < REGISTER_SIZES.len() => encode_hex(&register_bytes(cpu, number)),
+        _ => error_reply(),
+    }
+}
+
+fn write_register<M: MemoryBus>(cpu: &mut CPU<M>, args: &str) -> String {
+    let Some((number, value)) = args.split_once('=') else {
+        return error_reply();
+    };
+    let number = match parse_hex::<usize>(number) {
+        Some(number) if number < REGISTER_SIZES.len() => number,
+        _ => return error_reply(),
+    };
+    match decode_hex(value) {
+        Some(bytes) if bytes.len() == REGISTER_SIZES[number] => {
+            set_register(cpu, number, &bytes);
+            "OK".to_string()
+        }
+        _ => error_reply(),
+    }
+}
+
+// ========== Memory ==========
+
+/// Parse `addr,length` as used by `m` and `M` packets.
+///
+/// Each byte takes two hex digits on the wire, so the length is capped at
+/// half the packet size for the `m` reply and the `M` request to fit.
+fn parse_range(args: &str) -> Option<(u16, usize)> {
+    let (addr, length) = args.split_once(',')?;
+    let addr = parse_hex(addr)?;
+    let length = parse_hex(length)?;
+    (length <= PACKET_SIZE / 2).then_some((addr, length))
+}
+
+fn read_memory<M: MemoryBus>(cpu: &CPU<M>, args: &str) -> String {
+    let Some((addr, length)) = parse_range(args) else {
+        return error_reply();
+    };
+    let bytes: Vec<u8> = (0..length)
+        .map(|i| cpu.memory().peek(addr.wrapping_add(i as u16)))
+        .collect();
+    encode_hex(&bytes)
+}
+
+fn write_memory<M: MemoryBus>(cpu: &mut CPU<M>, args: &str) -> String {
+    let Some((range, data)) = args.split_once(':') else {
+        return error_reply();
+    };
+    let (Some((addr, length)), Some(bytes)) = (parse_range(range), decode_hex(data)) else {
+        return error_reply();
+    };
+    if bytes.len() != length {
+        return error_reply();
+    }
+
+    for (i, &byte) in bytes.iter().enumerate() {
+        cpu.memory_mut().write(addr.wrapping_add(i as u16), byte);
+    }
+    "OK".to_string()
+}
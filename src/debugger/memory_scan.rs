@@ -0,0 +1,403 @@
+//! Pattern search over emulated memory.
+//!
+//! [`BytePattern`] describes what to look for: exact bytes, a little-endian
+//! word, text in ASCII, PETSCII or screen codes, or a code signature with
+//! wildcard bytes standing in for operands that change when code is
+//! relocated. [`scan`] then walks any `MemoryBus` lazily and yields one match
+//! address at a time, so scanning the full 64KB address space never builds a
+//! copy of memory or a result list.
+//!
+//! [`refine`] narrows an earlier result set to the addresses that still match,
+//! for incremental searches such as tracking down a value as it changes.
+
+use crate::petscii::{self, CharSet};
+use crate::MemoryBus;
+use std::ops::RangeInclusive;
+
+/// A sequence of bytes to search for, where `None` matches any byte.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::debugger::BytePattern;
+///
+/// // LDA #imm / STA abs, with the operands left as wildcards
+/// let pattern = BytePattern::parse("A9 ?? 8D ?? ??").unwrap();
+/// assert_eq!(pattern.len(), 5);
+/// assert_eq!(pattern, BytePattern::new(vec![Some(0xA9), None, Some(0x8D), None, None]));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BytePattern {
+    bytes: Vec<Option<u8>>,
+}
+
+impl BytePattern {
+    /// Create a pattern from explicit bytes and wildcards.
+    pub fn new(bytes: Vec<Option<u8>>) -> Self {
+        Self { bytes }
+    }
+
+    /// Create a pattern that matches `bytes` exactly.
+    pub fn exact(bytes: &[u8]) -> Self {
+        Self::new(bytes.iter().copied().map(Some).collect())
+    }
+
+    /// Create a pattern matching a 16-bit value stored little-endian.
+    ///
+    /// Useful for finding pointers and vectors that refer to an address.
+    pub fn word(value: u16) -> Self {
+        Self::exact(&value.to_le_bytes())
+    }
+
+    /// Create a pattern matching the raw bytes of an ASCII string.
+    pub fn text(text: &str) -> Self {
+        Self::exact(text.as_bytes())
+    }
+
+    /// Create a pattern matching text stored as PETSCII in character set `set`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BytePattern)` if every character has a PETSCII code in `set`
+    /// * `Err(String)` naming the first character that does not
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib6502::debugger::BytePattern;
+    /// use lib6502::petscii::CharSet;
+    ///
+    /// let pattern = BytePattern::petscii("Ready.", CharSet::Shifted).unwrap();
+    /// assert_eq!(pattern, BytePattern::exact(&[0xD2, 0x45, 0x41, 0x44, 0x59, 0x2E]));
+    /// ```
+    pub fn petscii(text: &str, set: CharSet) -> Result<Self, String> {
+        petscii::encode(text, set)
+            .map(|bytes| Self::exact(&bytes))
+            .map_err(|c| format!("'{}' has no PETSCII code in the {:?} set", c, set))
+    }
+
+    /// Create a pattern matching text stored as screen codes in character
+    /// set `set`, as found in screen memory. Reverse video is not matched.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BytePattern)` if every character has a screen code in `set`
+    /// * `Err(String)` naming the first character that does not
+    pub fn screen_codes(text: &str, set: CharSet) -> Result<Self, String> {
+        text.chars()
+            .map(|c| {
+                petscii::char_to_screen_code(c, set)
+                    .map(Some)
+                    .ok_or_else(|| format!("'{}' has no screen code in the {:?} set", c, set))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+
+    /// Parse a pattern from whitespace-separated hex bytes.
+    ///
+    /// Each token is one or two hex digits, optionally prefixed with `$`.
+    /// `?` or `??` marks a wildcard byte.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(BytePattern)` if every token is valid
+    /// * `Err(String)` describing the first invalid token
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let bytes = source
+            .split_whitespace()
+            .map(|token| match token {
+                "?" | "??" => Ok(None),
+                _ => {
+                    let digits = token.strip_prefix('$').unwrap_or(token);
+                    if digits.is_empty()
+                        || digits.len() > 2
+                        || !digits.bytes().all(|b| b.is_ascii_hexdigit())
+                    {
+                        return Err(format!("Invalid pattern byte '{}'", token));
+                    }
+                    u8::from_str_radix(digits, 16)
+                        .map(Some)
+                        .map_err(|_| format!("Invalid pattern byte '{}'", token))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self::new(bytes))
+    }
+
+    /// Returns the number of bytes the pattern spans.
+    pub fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns true if the pattern contains no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Check whether the pattern matches memory starting at `addr`.
+    ///
+    /// Addresses wrap at $FFFF.
+    pub fn matches_at<M: MemoryBus>(&self, memory: &M, addr: u16) -> bool {
+        self.bytes
+            .iter()
+            .enumerate()
+            .all(|(i, expected)| match expected {
+                Some(byte) => memory.peek(addr.wrapping_add(i as u16)) == *byte,
+                None => true,
+            })
+    }
+}
+
+/// Lazily yields addresses where a [`BytePattern`] matches.
+///
+/// Created by [`scan`].
+pub struct MemoryScan<'a, M: MemoryBus> {
+    memory: &'a M,
+    pattern: &'a BytePattern,
+    /// Next candidate start address (u32 so the end of memory is representable)
+    next: u32,
+    /// Last start address at which the whole pattern still fits in range
+    last_start: u32,
+}
+
+impl<M: MemoryBus> Iterator for MemoryScan<'_, M> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        while self.next <= self.last_start {
+            let addr = self.next as u16;
+            self.next += 1;
+
+            if self.pattern.matches_at(self.memory, addr) {
+                return Some(addr);
+            }
+        }
+        None
+    }
+}
+
+/// Search `range` of memory for every occurrence of `pattern`.
+///
+/// A match is only reported if the entire pattern lies inside `range`.
+/// Overlapping matches are all reported. An empty pattern never matches.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::{FlatMemory, MemoryBus};
+/// use lib6502::debugger::{scan, BytePattern};
+///
+/// let mut mem = FlatMemory::new();
+/// for (i, byte) in b"HELLO".iter().enumerate() {
+///     mem.write(0x0400 + i as u16, *byte);
+/// }
+/// mem.write(0xFFFE, 0x00);
+/// mem.write(0xFFFF, 0x04);
+///
+/// let text = BytePattern::text("HELLO");
+/// assert_eq!(scan(&mem, &text, 0x0000..=0xFFFF).collect::<Vec<_>>(), vec![0x0400]);
+///
+/// // Find pointers to $0400
+/// let pointer = BytePattern::word(0x0400);
+/// assert_eq!(scan(&mem, &pointer, 0xFF00..=0xFFFF).next(), Some(0xFFFE));
+/// ```
+pub fn scan<'a, M: MemoryBus>(
+    memory: &'a M,
+    pattern: &'a BytePattern,
+    range: RangeInclusive<u16>,
+) -> MemoryScan<'a, M> {
+    let start = *range.start() as u32;
+    let end = *range.end() as u32;
+    let len = pattern.len() as u32;
+
+    // An empty pattern or one longer than the range yields nothing
+    let (next, last_start) = if len == 0 || start > end || end - start + 1 < len {
+        (1, 0)
+    } else {
+        (start, end + 1 - len)
+    };
+
+    MemoryScan {
+        memory,
+        pattern,
+        next,
+        last_start,
+    }
+}
+
+/// Keep the addresses from an earlier search where `pattern` still matches.
+///
+/// Re-checks `previous` (for example the collected output of [`scan`])
+/// against current memory, lazily and in order, so a search can be narrowed
+/// step by step as the program runs.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::{FlatMemory, MemoryBus};
+/// use lib6502::debugger::{refine, scan, BytePattern};
+///
+/// let mut mem = FlatMemory::new();
+/// mem.write(0x1000, 3);
+/// mem.write(0x2000, 3);
+///
+/// let lives = BytePattern::exact(&[3]);
+/// let candidates: Vec<u16> = scan(&mem, &lives, 0x0800..=0x2FFF).collect();
+/// assert_eq!(candidates, vec![0x1000, 0x2000]);
+///
+/// // The program loses a life
+/// mem.write(0x2000, 2);
+///
+/// let lives = BytePattern::exact(&[2]);
+/// assert_eq!(refine(&mem, &lives, candidates).collect::<Vec<_>>(), vec![0x2000]);
+/// ```
+pub fn refine<'a, M: MemoryBus>(
+    memory: &'a M,
+    pattern: &'a BytePattern,
+    previous: impl IntoIterator<Item = u16> + 'a,
+) -> impl Iterator<Item = u16> + 'a {
+    previous
+        .into_iter()
+        .filter(move |&addr| !pattern.is_empty() && pattern.matches_at(memory, addr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FlatMemory;
+
+    fn memory_with(addr: u16, bytes: &[u8]) -> FlatMemory {
+        let mut mem = FlatMemory::new();
+        for (i, byte) in bytes.iter().enumerate() {
+            mem.write(addr.wrapping_add(i as u16), *byte);
+        }
+        mem
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_tokens() {
+        assert!(BytePattern::parse("A9 XY").is_err());
+        assert!(BytePattern::parse("123").is_err());
+        assert!(BytePattern::parse("$").is_err());
+        assert_eq!(BytePattern::parse("$a9 0 ?").unwrap().len(), 3);
+        assert!(BytePattern::parse("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_scan_wildcard_signature() {
+        // LDA #$01 / STA $D020 at $C000, LDA #$07 / STA $0400 at $C100
+        let mut mem = memory_with(0xC000, &[0xA9, 0x01, 0x8D, 0x20, 0xD0]);
+        for (i, byte) in [0xA9, 0x07, 0x8D, 0x00, 0x04].iter().enumerate() {
+            mem.write(0xC100 + i as u16, *byte);
+        }
+
+        let pattern = BytePattern::parse("A9 ?? 8D ?? ??").unwrap();
+        let matches: Vec<u16> = scan(&mem, &pattern, 0x0000..=0xFFFF).collect();
+        assert_eq!(matches, vec![0xC000, 0xC100]);
+    }
+
+    #[test]
+    fn test_scan_reports_overlapping_matches() {
+        let mem = memory_with(0x2000, &[0xAA, 0xAA, 0xAA]);
+        let pattern = BytePattern::exact(&[0xAA, 0xAA]);
+
+        let matches: Vec<u16> = scan(&mem, &pattern, 0x2000..=0x2FFF).collect();
+        assert_eq!(matches, vec![0x2000, 0x2001]);
+    }
+
+    #[test]
+    fn test_scan_respects_range_end() {
+        let mem = memory_with(0xFFFE, &[0x12, 0x34]);
+        let pattern = BytePattern::exact(&[0x12, 0x34]);
+
+        assert_eq!(scan(&mem, &pattern, 0xFF00..=0xFFFF).next(), Some(0xFFFE));
+        // Pattern would run past the end of the range
+        assert_eq!(scan(&mem, &pattern, 0xFF00..=0xFFFE).next(), None);
+    }
+
+    #[test]
+    fn test_scan_degenerate_inputs() {
+        let mem = FlatMemory::new();
+
+        let empty = BytePattern::new(Vec::new());
+        assert_eq!(scan(&mem, &empty, 0x0000..=0xFFFF).count(), 0);
+
+        let long = BytePattern::exact(&[0x00; 4]);
+        assert_eq!(scan(&mem, &long, 0x1000..=0x1002).count(), 0);
+
+        // Full-range scan of zeroed memory visits the very last start address
+        let zero = BytePattern::exact(&[0x00]);
+        assert_eq!(scan(&mem, &zero, 0x0000..=0xFFFF).count(), 0x10000);
+    }
+
+    #[test]
+    fn test_scan_does_not_consume_uart_rx() {
+        use crate::{MappedMemory, RamDevice, Uart6551};
+
+        let mut memory = MappedMemory::new();
+        memory
+            .add_device(0x0000, Box::new(RamDevice::new(0x8000)))
+            .unwrap();
+        let mut uart = Uart6551::new();
+        uart.receive_byte(0x41);
+        uart.receive_byte(0x42);
+        memory.add_device(0xA000, Box::new(uart)).unwrap();
+
+        let pattern = BytePattern::exact(&[0x41]);
+        let matches: Vec<u16> = scan(&memory, &pattern, 0x0000..=0xFFFF).collect();
+        assert_eq!(matches, vec![0xA000]);
+
+        // Both received bytes are still waiting for the program
+        assert_eq!(memory.read(0xA000), 0x41);
+        assert_eq!(memory.read(0xA000), 0x42);
+    }
+
+    #[test]
+    fn test_petscii_and_screen_code_patterns() {
+        // "HI" as unshifted screen codes at $0400, as PETSCII at $C000
+        let mut mem = memory_with(0x0400, &[0x08, 0x09]);
+        mem.write(0xC000, 0x48);
+        mem.write(0xC001, 0x49);
+
+        let screen = BytePattern::screen_codes("HI", CharSet::Unshifted).unwrap();
+        assert_eq!(
+            scan(&mem, &screen, 0x0000..=0xFFFF).collect::<Vec<_>>(),
+            vec![0x0400]
+        );
+
+        let text = BytePattern::petscii("HI", CharSet::Unshifted).unwrap();
+        assert_eq!(
+            scan(&mem, &text, 0x0000..=0xFFFF).collect::<Vec<_>>(),
+            vec![0xC000]
+        );
+
+        // Lowercase only exists in the shifted set
+        assert!(BytePattern::petscii("hi", CharSet::Unshifted).is_err());
+        assert_eq!(
+            BytePattern::screen_codes("hi", CharSet::Shifted),
+            Ok(BytePattern::exact(&[0x08, 0x09]))
+        );
+    }
+
+    #[test]
+    fn test_refine_keeps_addresses_that_still_match() {
+        let mut mem = memory_with(0x1000, &[0x05]);
+        mem.write(0x1800, 0x05);
+        mem.write(0x2000, 0x05);
+
+        let pattern = BytePattern::exact(&[0x05]);
+        let first: Vec<u16> = scan(&mem, &pattern, 0x0800..=0x2FFF).collect();
+        assert_eq!(first, vec![0x1000, 0x1800, 0x2000]);
+
+        mem.write(0x1000, 0x04);
+        mem.write(0x2000, 0x04);
+        let decremented = BytePattern::exact(&[0x04]);
+        let second: Vec<u16> = refine(&mem, &decremented, first).collect();
+        assert_eq!(second, vec![0x1000, 0x2000]);
+
+        let empty = BytePattern::new(Vec::new());
+        assert_eq!(refine(&mem, &empty, second).count(), 0);
+    }
+}
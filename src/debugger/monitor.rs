@@ -0,0 +1,816 @@
+//! Text-based machine-code monitor.
+//!
+//! [`Monitor`] accepts VICE-style command lines and returns the output as a
+//! string. It does not read or write a terminal itself, so a native CLI and a
+//! browser console can embed it the same way. Numbers are hexadecimal, with
+//! an optional `$` prefix.
+//!
+//! | Command                  | Action                                        |
+//! |--------------------------|-----------------------------------------------|
+//! | `r`                      | Show registers                                |
+//! | `r A=01 X=02 ...`        | Set registers (A, X, Y, SP, PC, P)            |
+//! | `m [start [end]]`        | Hex dump memory                               |
+//! | `> addr byte...`         | Write bytes to memory                         |
+//! | `d [start [end]]`        | Disassemble                                   |
+//! | `a addr instruction`     | Assemble one instruction                      |
+//! | `f start end byte...`    | Fill a range with a byte sequence             |
+//! | `h start end pattern`    | Hunt for a byte pattern (`??` is a wildcard)  |
+//! | `hr [pattern]`           | Re-check the last hunt's matches              |
+//! | `c start end dest`       | Compare two ranges                            |
+//! | `break [addr]`           | Add a breakpoint, or list breakpoints         |
+//! | `del [addr]`             | Delete one breakpoint, or all of them         |
+//! | `z [count]`              | Step instructions                             |
+//! | `g [addr]`               | Run until a breakpoint or error               |
+//!
+//! A hunt pattern is either hex bytes or quoted text: `"text"` is ASCII,
+//! `p"text"` PETSCII and `s"text"` screen codes. PETSCII and screen-code text
+//! uses the unshifted (uppercase/graphics) character set unless it contains
+//! characters only the shifted set has. `hr` keeps the addresses from the
+//! previous hunt where the pattern (by default the same one) still matches,
+//! so a search can be narrowed while the program runs.
+//!
+//! `g` does not execute anything itself: it marks the monitor as running, and
+//! the host then calls [`Monitor::run`] with an instruction budget, as often as
+//! it likes, until a stop is reported. This keeps a browser host responsive
+//! while the program runs. Any other command pauses a running program first.
+//!
+//! Memory banking is machine-specific, so there is no bank command; the
+//! monitor sees whatever the `MemoryBus` maps at each address.
+
+use crate::assembler::assemble_with_origin;
+use crate::debugger::{refine, scan, BytePattern};
+use crate::disassembler::decoder::decode_instruction;
+use crate::disassembler::formatter::format_instruction;
+use crate::petscii::CharSet;
+use crate::{MemoryBus, CPU};
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// Bytes shown per line by the `m` command.
+const DUMP_BYTES_PER_LINE: u16 = 16;
+
+/// Bytes dumped by `m` when no end address is given.
+const DEFAULT_DUMP_LENGTH: u16 = 0x80;
+
+/// Instructions disassembled by `d` when no end address is given.
+const DEFAULT_DISASSEMBLY_LINES: usize = 16;
+
+/// Interactive monitor state: breakpoints, "continue from here" addresses and
+/// the last hunt's matches.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::{CPU, FlatMemory, MemoryBus};
+/// use lib6502::debugger::Monitor;
+///
+/// let mut mem = FlatMemory::new();
+/// mem.write(0xFFFC, 0x00);
+/// mem.write(0xFFFD, 0x80);
+///
+/// let mut cpu = CPU::new(mem);
+/// let mut monitor = Monitor::new();
+///
+/// monitor.execute(&mut cpu, "a 8000 LDA #$42");
+/// monitor.execute(&mut cpu, "a 8002 STA $0200");
+/// assert_eq!(monitor.execute(&mut cpu, "d 8000 8002"), "8000  A9 42     LDA #$42\n8002  8D 00 02  STA $0200");
+///
+/// monitor.execute(&mut cpu, "z 2");
+/// assert_eq!(monitor.execute(&mut cpu, "m 0200 0200"), "0200  42                                               |B|");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Monitor {
+    breakpoints: BTreeSet<u16>,
+    next_dump: Option<u16>,
+    next_disassembly: Option<u16>,
+    running: bool,
+    last_hunt: Option<(BytePattern, Vec<u16>)>,
+}
+
+impl Default for Monitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Monitor {
+    /// Create a monitor with no breakpoints.
+    pub fn new() -> Self {
+        Self {
+            breakpoints: BTreeSet::new(),
+            next_dump: None,
+            next_disassembly: None,
+            running: false,
+            last_hunt: None,
+        }
+    }
+
+    /// Returns true between a `g` command and the stop it leads to.
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Returns the breakpoint addresses in ascending order.
+    pub fn breakpoints(&self) -> impl Iterator<Item = u16> + '_ {
+        self.breakpoints.iter().copied()
+    }
+
+    /// Execute one command line against `cpu` and return its output.
+    ///
+    /// Errors are reported in the returned text, prefixed with `Error:`.
+    pub fn execute<M: MemoryBus>(&mut self, cpu: &mut CPU<M>, command: &str) -> String {
+        self.running = false;
+
+        let command = command.trim();
+        let (name, args) = match command.split_once(char::is_whitespace) {
+            Some((name, args)) => (name, args.trim()),
+            None => (command, ""),
+        };
+
+        let result = match name.to_ascii_lowercase().as_str() {
+            "" => Ok(String::new()),
+            "r" => self.registers(cpu, args),
+            "m" => self.memory_dump(cpu, args),
+            ">" => self.modify(cpu, args),
+            "d" => self.disassemble(cpu, args),
+            "a" => self.assemble(cpu, args),
+            "f" => self.fill(cpu, args),
+            "h" => self.hunt(cpu, args),
+            "hr" => self.refine_hunt(cpu, args),
+            "c" => self.compare(cpu, args),
+            "break" => self.add_breakpoint(args),
+            "del" => self.delete_breakpoint(args),
+            "z" => self.step(cpu, args),
+            "g" => self.go(cpu, args),
+            "help" | "?" => Ok(HELP.to_string()),
+            _ => Err(format!("Unknown command '{}'", name)),
+        };
+
+        result.unwrap_or_else(|message| format!("Error: {}", message))
+    }
+
+    /// Execute up to `max_instructions` while a `g` command is in effect.
+    ///
+    /// Returns the output to show when the program stops at a breakpoint or
+    /// on an error, and `None` if it is still running (or was not running at
+    /// all). The first instruction always executes, so `g` can leave a
+    /// breakpoint.
+    pub fn run<M: MemoryBus>(&mut self, cpu: &mut CPU<M>, max_instructions: u64) -> Option<String> {
+        if !self.running {
+            return None;
+        }
+
+        for _ in 0..max_instructions {
+            if let Err(e) = cpu.step() {
+                self.running = false;
+                return Some(format!("Error: {}", e));
+            }
+            if self.breakpoints.contains(&cpu.pc()) {
+                self.running = false;
+                return Some(format!(
+                    "Breakpoint at {:04X}\n{}",
+                    cpu.pc(),
+                    format_registers(cpu)
+                ));
+            }
+        }
+
+        None
+    }
+
+    // ========== Commands ==========
+
+    fn registers<M: MemoryBus>(&mut self, cpu: &mut CPU<M>, args: &str) -> Result<String, String> {
+        for assignment in args.split([',', ' ']).filter(|s| !s.is_empty()) {
+            let (register, value) = assignment
+                .split_once('=')
+                .ok_or_else(|| format!("Expected REG=VALUE, got '{}'", assignment))?;
+            let value = parse_number(value)?;
+
+            match register.to_ascii_uppercase().as_str() {
+                "PC" => cpu.set_pc(value),
+                register => {
+                    let byte = to_byte(value)?;
+                    match register {
+                        "A" => cpu.set_a(byte),
+                        "X" => cpu.set_x(byte),
+                        "Y" => cpu.set_y(byte),
+                        "SP" => cpu.set_sp(byte),
+                        "P" => set_status(cpu, byte),
+                        _ => return Err(format!("Unknown register '{}'", register)),
+                    }
+                }
+            }
+        }
+
+        Ok(format_registers(cpu))
+    }
+
+    fn memory_dump<M: MemoryBus>(&mut self, cpu: &CPU<M>, args: &str) -> Result<String, String> {
+        let numbers = parse_numbers(args)?;
+        let start = match numbers.first() {
+            Some(&start) => start,
+            None => self.next_dump.unwrap_or(cpu.pc()),
+        };
+        let end = match numbers.get(1) {
+            Some(&end) => end,
+            None => start.saturating_add(DEFAULT_DUMP_LENGTH - 1),
+        };
+        check_range(start, end)?;
+
+        let mut output = String::new();
+        let mut line_start = start as u32;
+        while line_start <= end as u32 {
+            let line_end = (line_start + DUMP_BYTES_PER_LINE as u32 - 1).min(end as u32);
+            let bytes: Vec<u8> = (line_start..=line_end)
+                .map(|addr| cpu.memory().peek(addr as u16))
+                .collect();
+
+            let hex: Vec<String> = bytes.iter().map(|b| format!("{:02X}", b)).collect();
+            let text: String = bytes
+                .iter()
+                .map(|&b| {
+                    if b.is_ascii_graphic() || b == b' ' {
+                        b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+
+            if !output.is_empty() {
+                output.push('\n');
+            }
+            let _ = write!(
+                output,
+                "{:04X}  {:<47}  |{}|",
+                line_start,
+                hex.join(" "),
+                text
+            );
+
+            line_start = line_end + 1;
+        }
+
+        self.next_dump = Some((end as u32 + 1) as u16);
+        Ok(output)
+    }
+
+    fn modify<M: MemoryBus>(&mut self, cpu: &mut CPU<M>, args: &str) -> Result<String, String> {
+        let numbers = parse_numbers(args)?;
+        let (&addr, bytes) = numbers.split_first().ok_or("Usage: > addr byte...")?;
+        if bytes.is_empty() {
+            return Err("Usage: > addr byte...".to_string());
+        }
+
+        // Validate every byte before writing any of them
+        let bytes = bytes
+            .iter()
+            .map(|&value| to_byte(value))
+            .collect::<Result<Vec<u8>, String>>()?;
+
+        for (i, &byte) in bytes.iter().enumerate() {
+            cpu.memory_mut().write(addr.wrapping_add(i as u16), byte);
+        }
+        Ok(String::new())
+    }
+
+    fn disassemble<M: MemoryBus>(&mut self, cpu: &CPU<M>, args: &str) -> Result<String, String> {
+        let numbers = parse_numbers(args)?;
+        let start = match numbers.first() {
+            Some(&start) => start,
+            None => self.next_disassembly.unwrap_or(cpu.pc()),
+        };
+        let end = numbers.get(1).copied();
+        if let Some(end) = end {
+            check_range(start, end)?;
+        }
+
+        let mut lines = Vec::new();
+        let mut addr = start as u32;
+        loop {
+            let done = match end {
+                Some(end) => addr > end as u32,
+                None => lines.len() == DEFAULT_DISASSEMBLY_LINES,
+            };
+            if done || addr > 0xFFFF {
+                break;
+            }
+
+            let (line, size) = disassemble_at(cpu.memory(), addr as u16);
+            lines.push(line);
+            addr += size as u32;
+        }
+
+        self.next_disassembly = Some(addr as u16);
+        Ok(lines.join("\n"))
+    }
+
+    fn assemble<M: MemoryBus>(&mut self, cpu: &mut CPU<M>, args: &str) -> Result<String, String> {
+        let (addr, source) = args
+            .split_once(char::is_whitespace)
+            .ok_or("Usage: a addr instruction")?;
+        let addr = parse_number(addr)?;
+
+        let output = assemble_with_origin(source.trim(), addr).map_err(|errors| {
+            errors
+                .first()
+                .map(|e| e.message.clone())
+                .unwrap_or_else(|| "Assembly failed".to_string())
+        })?;
+
+        for (i, &byte) in output.bytes.iter().enumerate() {
+            cpu.memory_mut().write(addr.wrapping_add(i as u16), byte);
+        }
+
+        let (line, size) = disassemble_at(cpu.memory(), addr);
+        self.next_disassembly = Some(addr.wrapping_add(size as u16));
+        Ok(line)
+    }
+
+    fn fill<M: MemoryBus>(&mut self, cpu: &mut CPU<M>, args: &str) -> Result<String, String> {
+        let numbers = parse_numbers(args)?;
+        if numbers.len() < 3 {
+            return Err("Usage: f start end byte...".to_string());
+        }
+        let (start, end) = (numbers[0], numbers[1]);
+        check_range(start, end)?;
+        let pattern = numbers[2..]
+            .iter()
+            .map(|&value| to_byte(value))
+            .collect::<Result<Vec<u8>, String>>()?;
+
+        for (addr, &byte) in (start..=end).zip(pattern.iter().cycle()) {
+            cpu.memory_mut().write(addr, byte);
+        }
+        Ok(String::new())
+    }
+
+    fn hunt<M: MemoryBus>(&mut self, cpu: &CPU<M>, args: &str) -> Result<String, String> {
+        let mut parts = args.splitn(3, char::is_whitespace);
+        let (Some(start), Some(end), Some(pattern)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err("Usage: h start end pattern".to_string());
+        };
+        let (start, end) = (parse_number(start)?, parse_number(end)?);
+        check_range(start, end)?;
+
+        let pattern = parse_hunt_pattern(pattern)?;
+        let matches: Vec<u16> = scan(cpu.memory(), &pattern, start..=end).collect();
+        let output = format_addresses(&matches);
+        self.last_hunt = Some((pattern, matches));
+        Ok(output)
+    }
+
+    fn refine_hunt<M: MemoryBus>(&mut self, cpu: &CPU<M>, args: &str) -> Result<String, String> {
+        let pattern = if args.is_empty() {
+            None
+        } else {
+            Some(parse_hunt_pattern(args)?)
+        };
+        let Some((last_pattern, previous)) = self.last_hunt.take() else {
+            return Err("No previous hunt to refine".to_string());
+        };
+        let pattern = pattern.unwrap_or(last_pattern);
+
+        let matches: Vec<u16> = refine(cpu.memory(), &pattern, previous).collect();
+        let output = format_addresses(&matches);
+        self.last_hunt = Some((pattern, matches));
+        Ok(output)
+    }
+
+    fn compare<M: MemoryBus>(&mut self, cpu: &CPU<M>, args: &str) -> Result<String, String> {
+        let numbers = parse_numbers(args)?;
+        let [start, end, dest] = numbers[..] else {
+            return Err("Usage: c start end dest".to_string());
+        };
+        check_range(start, end)?;
+
+        let differences: Vec<String> = (start..=end)
+            .filter_map(|addr| {
+                let other = dest.wrapping_add(addr - start);
+                let (a, b) = (cpu.memory().peek(addr), cpu.memory().peek(other));
+                (a != b).then(|| format!("{:04X}: {:02X}  {:04X}: {:02X}", addr, a, other, b))
+            })
+            .collect();
+        Ok(differences.join("\n"))
+    }
+
+    fn add_breakpoint(&mut self, args: &str) -> Result<String, String> {
+        if args.is_empty() {
+            let list: Vec<String> = self
+                .breakpoints
+                .iter()
+                .map(|addr| format!("{:04X}", addr))
+                .collect();
+            return Ok(list.join("\n"));
+        }
+
+        let addr = parse_number(args)?;
+        self.breakpoints.insert(addr);
+        Ok(format!("Breakpoint at {:04X}", addr))
+    }
+
+    fn delete_breakpoint(&mut self, args: &str) -> Result<String, String> {
+        if args.is_empty() {
+            self.breakpoints.clear();
+            return Ok("All breakpoints deleted".to_string());
+        }
+
+        let addr = parse_number(args)?;
+        if self.breakpoints.remove(&addr) {
+            Ok(format!("Breakpoint at {:04X} deleted", addr))
+        } else {
+            Err(format!("No breakpoint at {:04X}", addr))
+        }
+    }
+
+    fn step<M: MemoryBus>(&mut self, cpu: &mut CPU<M>, args: &str) -> Result<String, String> {
+        let count = if args.is_empty() {
+            1
+        } else {
+            parse_number(args)?
+        };
+
+        for _ in 0..count {
+            cpu.step().map_err(|e| e.to_string())?;
+        }
+
+        self.next_disassembly = None;
+        Ok(format!(
+            "{}\n{}",
+            disassemble_at(cpu.memory(), cpu.pc()).0,
+            format_registers(cpu)
+        ))
+    }
+
+    fn go<M: MemoryBus>(&mut self, cpu: &mut CPU<M>, args: &str) -> Result<String, String> {
+        if !args.is_empty() {
+            cpu.set_pc(parse_number(args)?);
+        }
+
+        self.running = true;
+        self.next_disassembly = None;
+        Ok(String::new())
+    }
+}
+
+const HELP: &str = "\
+r [REG=VALUE...]       registers (A X Y SP PC P)
+m [start [end]]        memory dump
+> addr byte...         write memory
+d [start [end]]        disassemble
+a addr instruction     assemble
+f start end byte...    fill
+h start end pattern    hunt (?? = any byte, \"ascii\", p\"petscii\", s\"screen\")
+hr [pattern]           re-check last hunt's matches
+c start end dest       compare
+break [addr]           add/list breakpoints
+del [addr]             delete breakpoint(s)
+z [count]              step
+g [addr]               run to breakpoint";
+
+// ========== Helpers ==========
+
+/// Parse a hexadecimal number with an optional `$` prefix.
+fn parse_number(text: &str) -> Result<u16, String> {
+    let digits = text.trim().strip_prefix('$').unwrap_or(text.trim());
+    u16::from_str_radix(digits, 16).map_err(|_| format!("Invalid number '{}'", text.trim()))
+}
+
+/// Parse whitespace-separated hexadecimal numbers.
+fn parse_numbers(text: &str) -> Result<Vec<u16>, String> {
+    text.split_whitespace().map(parse_number).collect()
+}
+
+/// Parse a hunt pattern: hex bytes, or quoted ASCII (`"..."`), PETSCII
+/// (`p"..."`) or screen-code (`s"..."`) text.
+fn parse_hunt_pattern(text: &str) -> Result<BytePattern, String> {
+    let (encoding, quoted) = match text.as_bytes().first() {
+        Some(b'p' | b'P' | b's' | b'S') if text[1..].starts_with('"') => {
+            (text[..1].to_ascii_lowercase(), &text[1..])
+        }
+        Some(b'"') => (String::new(), text),
+        _ => return BytePattern::parse(text),
+    };
+    let Some(body) = quoted
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    else {
+        return Err(format!("Unterminated text in pattern '{}'", text));
+    };
+    if body.is_empty() {
+        return Err("Empty text pattern".to_string());
+    }
+
+    let encode: fn(&str, CharSet) -> Result<BytePattern, String> = match encoding.as_str() {
+        "" => return Ok(BytePattern::text(body)),
+        "p" => BytePattern::petscii,
+        _ => BytePattern::screen_codes,
+    };
+    encode(body, CharSet::Unshifted).or_else(|_| encode(body, CharSet::Shifted))
+}
+
+fn format_addresses(addresses: &[u16]) -> String {
+    let lines: Vec<String> = addresses
+        .iter()
+        .map(|addr| format!("{:04X}", addr))
+        .collect();
+    lines.join("\n")
+}
+
+fn to_byte(value: u16) -> Result<u8, String> {
+    u8::try_from(value).map_err(|_| format!("Value ${:X} does not fit in a byte", value))
+}
+
+fn check_range(start: u16, end: u16) -> Result<(), String> {
+    if start > end {
+        return Err(format!("Start {:04X} is after end {:04X}", start, end));
+    }
+    Ok(())
+}
+
+/// Disassemble the instruction at `addr`, returning the line and its size.
+fn disassemble_at<M: MemoryBus>(memory: &M, addr: u16) -> (String, u8) {
+    let bytes: Vec<u8> = (0..3).map(|i| memory.peek(addr.wrapping_add(i))).collect();
+
+    match decode_instruction(&bytes, addr) {
+        Some(instruction) => {
+            let hex: Vec<String> = instruction.operand_bytes.iter().fold(
+                vec![format!("{:02X}", instruction.opcode)],
+                |mut hex, b| {
+                    hex.push(format!("{:02X}", b));
+                    hex
+                },
+            );
+            (
+                format!(
+                    "{:04X}  {:<8}  {}",
+                    addr,
+                    hex.join(" "),
+                    format_instruction(&instruction)
+                ),
+                instruction.size_bytes,
+            )
+        }
+        None => (
+            format!(
+                "{:04X}  {:<8}  .byte ${:02X}",
+                addr,
+                format!("{:02X}", bytes[0]),
+                bytes[0]
+            ),
+            1,
+        ),
+    }
+}
+
+fn format_registers<M: MemoryBus>(cpu: &CPU<M>) -> String {
+    format!(
+        "PC={:04X} A={:02X} X={:02X} Y={:02X} SP={:02X} NV-BDIZC={:08b} CYC={}",
+        cpu.pc(),
+        cpu.a(),
+        cpu.x(),
+        cpu.y(),
+        cpu.sp(),
+        cpu.status(),
+        cpu.cycles()
+    )
+}
+
+/// Unpack a status byte into the individual CPU flags.
+fn set_status<M: MemoryBus>(cpu: &mut CPU<M>, status: u8) {
+    cpu.set_flag_n(status & 0x80 != 0);
+    cpu.set_flag_v(status & 0x40 != 0);
+    cpu.set_flag_b(status & 0x10 != 0);
+    cpu.set_flag_d(status & 0x08 != 0);
+    cpu.set_flag_i(status & 0x04 != 0);
+    cpu.set_flag_z(status & 0x02 != 0);
+    cpu.set_flag_c(status & 0x01 != 0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FlatMemory;
+
+    fn setup_cpu() -> CPU<FlatMemory> {
+        let mut memory = FlatMemory::new();
+        memory.write(0xFFFC, 0x00);
+        memory.write(0xFFFD, 0x80);
+        CPU::new(memory)
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number("c000"), Ok(0xC000));
+        assert_eq!(parse_number("$FF"), Ok(0xFF));
+        assert!(parse_number("xyz").is_err());
+        assert!(parse_number("10000").is_err());
+    }
+
+    #[test]
+    fn test_registers_set_and_show() {
+        let mut cpu = setup_cpu();
+        let mut monitor = Monitor::new();
+
+        let output = monitor.execute(&mut cpu, "r A=42 X=01, PC=C000 P=81");
+        assert_eq!(cpu.a(), 0x42);
+        assert_eq!(cpu.x(), 0x01);
+        assert_eq!(cpu.pc(), 0xC000);
+        assert!(cpu.flag_n() && cpu.flag_c() && !cpu.flag_i());
+        assert!(output.starts_with("PC=C000 A=42 X=01"));
+
+        assert!(monitor
+            .execute(&mut cpu, "r Q=1")
+            .starts_with("Error: Unknown register"));
+        assert!(monitor.execute(&mut cpu, "r A=100").starts_with("Error:"));
+    }
+
+    #[test]
+    fn test_memory_dump_continues() {
+        let mut cpu = setup_cpu();
+        let mut monitor = Monitor::new();
+
+        monitor.execute(&mut cpu, "> 1000 48 49");
+        let first = monitor.execute(&mut cpu, "m 1000 100f");
+        assert_eq!(
+            first,
+            "1000  48 49 00 00 00 00 00 00 00 00 00 00 00 00 00 00  |HI..............|"
+        );
+
+        let next = monitor.execute(&mut cpu, "m");
+        assert!(next.starts_with("1010  "));
+        assert_eq!(next.lines().count(), 8);
+    }
+
+    #[test]
+    fn test_memory_dump_leaves_device_state_alone() {
+        use crate::{MappedMemory, Uart6551};
+
+        let mut uart = Uart6551::new();
+        uart.receive_byte(0x41);
+        let mut memory = MappedMemory::new();
+        memory.add_device(0xA000, Box::new(uart)).unwrap();
+        let mut cpu = CPU::new(memory);
+        let mut monitor = Monitor::new();
+
+        let output = monitor.execute(&mut cpu, "m a000 a000");
+        assert!(output.starts_with("A000  41 "));
+        assert_eq!(cpu.memory().read(0xA000), 0x41);
+    }
+
+    #[test]
+    fn test_modify_validates_whole_line() {
+        let mut cpu = setup_cpu();
+        let mut monitor = Monitor::new();
+
+        assert_eq!(
+            monitor.execute(&mut cpu, "> 1000 11 22 123"),
+            "Error: Value $123 does not fit in a byte"
+        );
+        assert_eq!(cpu.memory().read(0x1000), 0x00);
+        assert_eq!(cpu.memory().read(0x1001), 0x00);
+    }
+
+    #[test]
+    fn test_fill_hunt_compare() {
+        let mut cpu = setup_cpu();
+        let mut monitor = Monitor::new();
+
+        monitor.execute(&mut cpu, "f 2000 2007 aa 55");
+        assert_eq!(cpu.memory().read(0x2006), 0xAA);
+        assert_eq!(cpu.memory().read(0x2007), 0x55);
+
+        assert_eq!(
+            monitor.execute(&mut cpu, "h 2000 20ff 55 aa"),
+            "2001\n2003\n2005"
+        );
+
+        monitor.execute(&mut cpu, "f 3000 3007 aa 55");
+        monitor.execute(&mut cpu, "> 3002 00");
+        assert_eq!(
+            monitor.execute(&mut cpu, "c 2000 2007 3000"),
+            "2002: AA  3002: 00"
+        );
+    }
+
+    #[test]
+    fn test_hunt_text_and_refine() {
+        let mut cpu = setup_cpu();
+        let mut monitor = Monitor::new();
+
+        // "HI" in ASCII, PETSCII (same codes) and unshifted screen codes
+        monitor.execute(&mut cpu, "> 1000 48 49");
+        monitor.execute(&mut cpu, "> 0400 08 09");
+        assert_eq!(monitor.execute(&mut cpu, "h 0000 1fff \"HI\""), "1000");
+        assert_eq!(monitor.execute(&mut cpu, "h 0000 1fff p\"HI\""), "1000");
+        assert_eq!(monitor.execute(&mut cpu, "h 0000 1fff s\"HI\""), "0400");
+
+        // Lowercase falls back to the shifted set: "hi" is $48 $49 there too
+        assert_eq!(monitor.execute(&mut cpu, "h 0000 1fff p\"hi\""), "1000");
+        assert_eq!(
+            monitor.execute(&mut cpu, "h 0000 1fff p\"HI"),
+            "Error: Unterminated text in pattern 'p\"HI'"
+        );
+
+        // Narrow a byte hunt as memory changes
+        monitor.execute(&mut cpu, "> 2000 03");
+        monitor.execute(&mut cpu, "> 2100 03");
+        monitor.execute(&mut cpu, "> 2200 03");
+        assert_eq!(
+            monitor.execute(&mut cpu, "h 2000 2fff 03"),
+            "2000\n2100\n2200"
+        );
+        monitor.execute(&mut cpu, "> 2100 02");
+        monitor.execute(&mut cpu, "> 2200 02");
+        assert_eq!(monitor.execute(&mut cpu, "hr 02"), "2100\n2200");
+        monitor.execute(&mut cpu, "> 2200 01");
+        assert_eq!(monitor.execute(&mut cpu, "hr"), "2100");
+        assert_eq!(
+            monitor.execute(&mut cpu, "hr zz"),
+            "Error: Invalid pattern byte 'zz'"
+        );
+        assert_eq!(monitor.execute(&mut cpu, "hr"), "2100");
+    }
+
+    #[test]
+    fn test_refine_without_hunt() {
+        let mut cpu = setup_cpu();
+        let mut monitor = Monitor::new();
+
+        assert_eq!(
+            monitor.execute(&mut cpu, "hr 00"),
+            "Error: No previous hunt to refine"
+        );
+    }
+
+    #[test]
+    fn test_breakpoints_and_run() {
+        let mut cpu = setup_cpu();
+        let mut monitor = Monitor::new();
+
+        // $8000: INX / JMP $8000
+        monitor.execute(&mut cpu, "a 8000 INX");
+        monitor.execute(&mut cpu, "a 8001 JMP $8000");
+
+        assert_eq!(
+            monitor.execute(&mut cpu, "break 8001"),
+            "Breakpoint at 8001"
+        );
+        monitor.execute(&mut cpu, "g");
+        assert!(monitor
+            .run(&mut cpu, 1000)
+            .unwrap()
+            .starts_with("Breakpoint at 8001"));
+        assert_eq!(cpu.x(), 1);
+
+        // Running again leaves the breakpoint and comes back around
+        monitor.execute(&mut cpu, "g");
+        assert!(monitor.run(&mut cpu, 1000).is_some());
+        assert_eq!(cpu.x(), 2);
+        assert_eq!(monitor.breakpoints().collect::<Vec<_>>(), vec![0x8001]);
+
+        // Without breakpoints the loop keeps running across calls until
+        // another command pauses it
+        monitor.execute(&mut cpu, "del");
+        assert_eq!(monitor.execute(&mut cpu, "g"), "");
+        assert_eq!(monitor.run(&mut cpu, 10), None);
+        assert_eq!(monitor.run(&mut cpu, 10), None);
+        assert_eq!(cpu.x(), 12);
+        assert!(monitor.is_running());
+
+        monitor.execute(&mut cpu, "r");
+        assert!(!monitor.is_running());
+        assert_eq!(monitor.run(&mut cpu, 10), None);
+        assert_eq!(cpu.x(), 12);
+    }
+
+    #[test]
+    fn test_step_reports_errors() {
+        let mut cpu = setup_cpu();
+        let mut monitor = Monitor::new();
+
+        monitor.execute(&mut cpu, "> 8000 ea 02");
+        assert!(monitor.execute(&mut cpu, "z").starts_with("8001  02"));
+        assert_eq!(
+            monitor.execute(&mut cpu, "z"),
+            "Error: Opcode 0x02 is not implemented"
+        );
+    }
+
+    #[test]
+    fn test_unknown_command_and_bad_assembly() {
+        let mut cpu = setup_cpu();
+        let mut monitor = Monitor::new();
+
+        assert_eq!(
+            monitor.execute(&mut cpu, "xyzzy"),
+            "Error: Unknown command 'xyzzy'"
+        );
+        assert!(monitor
+            .execute(&mut cpu, "a 8000 FOO")
+            .starts_with("Error:"));
+        assert_eq!(monitor.execute(&mut cpu, "   "), "");
+    }
+}
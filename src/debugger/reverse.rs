@@ -0,0 +1,264 @@
+//! Instruction-level undo (reverse stepping).
+//!
+//! [`JournaledMemory`] wraps any [`MemoryBus`] and records the previous value
+//! of every byte written. [`ReverseStepper`] steps a CPU built on that memory,
+//! keeping the register state and write journal of each instruction in a
+//! bounded history, and [`ReverseStepper::step_back`] rolls both back.
+//!
+//! Only writes to address ranges the caller marks as RAM are journaled. Writes
+//! to memory-mapped devices are left alone: replaying an old value into a
+//! device register would trigger its side effects again (a UART would
+//! transmit the byte), so stepping back cannot undo device state.
+
+use crate::{ExecutionError, MemoryBus, CPU};
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
+
+/// Memory bus wrapper that journals writes so they can be undone.
+///
+/// Each write inside one of the RAM ranges records `(address, old value)`,
+/// with the old value taken through [`MemoryBus::peek`] so journaling never
+/// disturbs a device. [`take_journal`] hands the entries accumulated since the
+/// last call to the caller.
+///
+/// [`take_journal`]: JournaledMemory::take_journal
+#[derive(Debug, Clone)]
+pub struct JournaledMemory<M: MemoryBus> {
+    inner: M,
+    ram: Vec<RangeInclusive<u16>>,
+    journal: Vec<(u16, u8)>,
+}
+
+impl<M: MemoryBus> JournaledMemory<M> {
+    /// Wrap `inner`, journaling writes that fall inside the `ram` ranges.
+    ///
+    /// Pass `[0x0000..=0xFFFF]` for memory with no devices, such as
+    /// `FlatMemory`.
+    pub fn new(inner: M, ram: impl IntoIterator<Item = RangeInclusive<u16>>) -> Self {
+        Self {
+            inner,
+            ram: ram.into_iter().collect(),
+            journal: Vec::new(),
+        }
+    }
+
+    /// Returns the wrapped memory.
+    pub fn inner(&self) -> &M {
+        &self.inner
+    }
+
+    /// Returns the wrapped memory mutably. Writes made this way are not journaled.
+    pub fn inner_mut(&mut self) -> &mut M {
+        &mut self.inner
+    }
+
+    /// Unwrap, discarding the journal.
+    pub fn into_inner(self) -> M {
+        self.inner
+    }
+
+    /// Remove and return the writes journaled since the last call, oldest first.
+    pub fn take_journal(&mut self) -> Vec<(u16, u8)> {
+        std::mem::take(&mut self.journal)
+    }
+
+    /// Write old values back, newest first, without journaling them.
+    fn undo(&mut self, writes: &[(u16, u8)]) {
+        for &(addr, old) in writes.iter().rev() {
+            self.inner.write(addr, old);
+        }
+    }
+}
+
+impl<M: MemoryBus> MemoryBus for JournaledMemory<M> {
+    fn read(&self, addr: u16) -> u8 {
+        self.inner.read(addr)
+    }
+
+    fn peek(&self, addr: u16) -> u8 {
+        self.inner.peek(addr)
+    }
+
+    fn write(&mut self, addr: u16, value: u8) {
+        if self.ram.iter().any(|range| range.contains(&addr)) {
+            self.journal.push((addr, self.inner.peek(addr)));
+        }
+        self.inner.write(addr, value);
+    }
+
+    fn irq_active(&self) -> bool {
+        self.inner.irq_active()
+    }
+
+    fn nmi_active(&self) -> bool {
+        self.inner.nmi_active()
+    }
+}
+
+/// CPU state restored by an undo.
+#[derive(Debug, Clone, Copy)]
+struct Registers {
+    a: u8,
+    x: u8,
+    y: u8,
+    pc: u16,
+    sp: u8,
+    status: u8,
+    cycles: u64,
+    irq_pending: bool,
+    nmi_line: bool,
+    nmi_pending: bool,
+}
+
+impl Registers {
+    fn capture<M: MemoryBus>(cpu: &CPU<M>) -> Self {
+        Self {
+            a: cpu.a,
+            x: cpu.x,
+            y: cpu.y,
+            pc: cpu.pc,
+            sp: cpu.sp,
+            status: cpu.status(),
+            cycles: cpu.cycles,
+            irq_pending: cpu.irq_pending,
+            nmi_line: cpu.nmi_line,
+            nmi_pending: cpu.nmi_pending,
+        }
+    }
+
+    fn restore<M: MemoryBus>(&self, cpu: &mut CPU<M>) {
+        cpu.a = self.a;
+        cpu.x = self.x;
+        cpu.y = self.y;
+        cpu.pc = self.pc;
+        cpu.sp = self.sp;
+        cpu.flag_n = self.status & 0x80 != 0;
+        cpu.flag_v = self.status & 0x40 != 0;
+        cpu.flag_b = self.status & 0x10 != 0;
+        cpu.flag_d = self.status & 0x08 != 0;
+        cpu.flag_i = self.status & 0x04 != 0;
+        cpu.flag_z = self.status & 0x02 != 0;
+        cpu.flag_c = self.status & 0x01 != 0;
+        cpu.cycles = self.cycles;
+        cpu.irq_pending = self.irq_pending;
+        cpu.nmi_line = self.nmi_line;
+        cpu.nmi_pending = self.nmi_pending;
+    }
+}
+
+/// One recorded instruction: the state before it ran and the bytes it changed.
+#[derive(Debug, Clone)]
+struct UndoRecord {
+    registers: Registers,
+    writes: Vec<(u16, u8)>,
+}
+
+/// Steps the CPU while keeping enough history to undo recent instructions.
+///
+/// An interrupt serviced at the end of a step is part of that step, so
+/// stepping back over it also undoes the interrupt entry.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::{CPU, FlatMemory, MemoryBus};
+/// use lib6502::debugger::{JournaledMemory, ReverseStepper};
+///
+/// let mut mem = FlatMemory::new();
+/// mem.write(0xFFFC, 0x00);
+/// mem.write(0xFFFD, 0x80);
+///
+/// // $8000: INC $10
+/// // $8002: INC $10
+/// mem.write(0x8000, 0xE6);
+/// mem.write(0x8001, 0x10);
+/// mem.write(0x8002, 0xE6);
+/// mem.write(0x8003, 0x10);
+///
+/// let mut cpu = CPU::new(JournaledMemory::new(mem, [0x0000..=0xFFFF]));
+/// let mut stepper = ReverseStepper::new(100);
+///
+/// stepper.step(&mut cpu).unwrap();
+/// stepper.step(&mut cpu).unwrap();
+/// assert_eq!(cpu.memory().read(0x10), 2);
+///
+/// assert_eq!(stepper.step_back(&mut cpu, 1), 1);
+/// assert_eq!(cpu.pc(), 0x8002);
+/// assert_eq!(cpu.memory().read(0x10), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReverseStepper {
+    history: VecDeque<UndoRecord>,
+    depth: usize,
+}
+
+impl ReverseStepper {
+    /// Create a stepper that can undo up to `depth` instructions.
+    pub fn new(depth: usize) -> Self {
+        Self {
+            history: VecDeque::with_capacity(depth),
+            depth,
+        }
+    }
+
+    /// Execute one instruction, recording how to undo it.
+    ///
+    /// Instructions that fail with an error are recorded too, since a failed
+    /// step still advances the PC and cycle count.
+    pub fn step<M: MemoryBus>(
+        &mut self,
+        cpu: &mut CPU<JournaledMemory<M>>,
+    ) -> Result<(), ExecutionError> {
+        let registers = Registers::capture(cpu);
+
+        // Drop writes made outside `step` so they are never undone
+        cpu.memory.take_journal();
+        let result = cpu.step();
+        let writes = cpu.memory.take_journal();
+
+        if self.depth > 0 {
+            if self.history.len() == self.depth {
+                self.history.pop_front();
+            }
+            self.history.push_back(UndoRecord { registers, writes });
+        }
+
+        result
+    }
+
+    /// Undo up to `count` instructions, newest first.
+    ///
+    /// Returns the number of instructions actually undone, which is less than
+    /// `count` when the history runs out.
+    pub fn step_back<M: MemoryBus>(
+        &mut self,
+        cpu: &mut CPU<JournaledMemory<M>>,
+        count: usize,
+    ) -> usize {
+        let mut undone = 0;
+        while undone < count {
+            let Some(record) = self.history.pop_back() else {
+                break;
+            };
+            cpu.memory.undo(&record.writes);
+            record.registers.restore(cpu);
+            undone += 1;
+        }
+        undone
+    }
+
+    /// Returns the number of instructions that can currently be undone.
+    pub fn len(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Returns true if there is nothing to undo.
+    pub fn is_empty(&self) -> bool {
+        self.history.is_empty()
+    }
+
+    /// Discard the undo history.
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+}
@@ -0,0 +1,190 @@
+//! Instruction trace logging.
+//!
+//! When a program crashes thousands of frames in, the interesting part is
+//! the last few hundred instructions before it went wrong. [`Tracer`] steps
+//! the CPU and keeps a fixed-depth ring buffer of [`TraceEntry`] records, so
+//! tracing can stay on for an entire session with bounded memory. Recording
+//! can be limited to a PC range to follow one routine.
+
+use crate::disassembler::decoder::decode_instruction;
+use crate::disassembler::formatter::format_instruction;
+use crate::{ExecutionError, MemoryBus, CPU, OPCODE_TABLE};
+use std::collections::VecDeque;
+use std::ops::RangeInclusive;
+
+/// CPU state captured just before an instruction executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEntry {
+    /// Address of the instruction
+    pub pc: u16,
+
+    /// Opcode byte
+    pub opcode: u8,
+
+    /// Operand bytes; only the first `size_bytes - 1` are meaningful
+    pub operands: [u8; 2],
+
+    /// Total instruction size in bytes (1-3)
+    pub size_bytes: u8,
+
+    /// Accumulator
+    pub a: u8,
+
+    /// X index register
+    pub x: u8,
+
+    /// Y index register
+    pub y: u8,
+
+    /// Stack pointer
+    pub sp: u8,
+
+    /// Packed status register (NV-BDIZC)
+    pub status: u8,
+
+    /// Cycle count before the instruction executed
+    pub cycles: u64,
+}
+
+impl TraceEntry {
+    /// Capture the state of `cpu` and the instruction at its PC.
+    pub fn capture<M: MemoryBus>(cpu: &CPU<M>) -> Self {
+        let pc = cpu.pc();
+        let opcode = cpu.memory().peek(pc);
+        let metadata = &OPCODE_TABLE[opcode as usize];
+
+        let mut operands = [0; 2];
+        for (i, operand) in operands
+            .iter_mut()
+            .enumerate()
+            .take(metadata.size_bytes.saturating_sub(1) as usize)
+        {
+            *operand = cpu.memory().peek(pc.wrapping_add(1 + i as u16));
+        }
+
+        Self {
+            pc,
+            opcode,
+            operands,
+            size_bytes: metadata.size_bytes,
+            a: cpu.a(),
+            x: cpu.x(),
+            y: cpu.y(),
+            sp: cpu.sp(),
+            status: cpu.status(),
+            cycles: cpu.cycles(),
+        }
+    }
+
+    /// Returns the instruction mnemonic ("???" for JAM opcodes).
+    pub fn mnemonic(&self) -> &'static str {
+        OPCODE_TABLE[self.opcode as usize].mnemonic
+    }
+
+    /// Disassemble the traced instruction, e.g. `LDA #$42`.
+    pub fn disassembly(&self) -> String {
+        let mut bytes = vec![self.opcode];
+        bytes.extend_from_slice(&self.operands[..self.size_bytes as usize - 1]);
+
+        match decode_instruction(&bytes, self.pc) {
+            Some(instruction) => format_instruction(&instruction),
+            None => format!(".byte ${:02X}", self.opcode),
+        }
+    }
+}
+
+/// Steps the CPU while recording a bounded instruction history.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::{CPU, FlatMemory, MemoryBus};
+/// use lib6502::debugger::Tracer;
+///
+/// let mut mem = FlatMemory::new();
+/// mem.write(0xFFFC, 0x00);
+/// mem.write(0xFFFD, 0x80);
+///
+/// // $8000: LDA #$42
+/// // $8002: TAX
+/// mem.write(0x8000, 0xA9);
+/// mem.write(0x8001, 0x42);
+/// mem.write(0x8002, 0xAA);
+///
+/// let mut cpu = CPU::new(mem);
+/// let mut tracer = Tracer::new(64);
+///
+/// tracer.step(&mut cpu).unwrap();
+/// tracer.step(&mut cpu).unwrap();
+///
+/// let entries: Vec<_> = tracer.entries().collect();
+/// assert_eq!(entries.len(), 2);
+/// assert_eq!(entries[0].disassembly(), "LDA #$42");
+/// assert_eq!(entries[1].pc, 0x8002);
+/// assert_eq!(entries[1].a, 0x42);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tracer {
+    entries: VecDeque<TraceEntry>,
+    depth: usize,
+    pc_filter: Option<RangeInclusive<u16>>,
+}
+
+impl Tracer {
+    /// Create a tracer that keeps the last `depth` recorded instructions.
+    pub fn new(depth: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(depth),
+            depth,
+            pc_filter: None,
+        }
+    }
+
+    /// Only record instructions whose address lies in `range`.
+    ///
+    /// Pass `None` to record every instruction again.
+    pub fn set_pc_filter(&mut self, range: Option<RangeInclusive<u16>>) {
+        self.pc_filter = range;
+    }
+
+    /// Record the next instruction (if it passes the filter) and execute it.
+    ///
+    /// The entry is recorded before execution, so an instruction that fails
+    /// with an error is still the last entry in the trace.
+    pub fn step<M: MemoryBus>(&mut self, cpu: &mut CPU<M>) -> Result<(), ExecutionError> {
+        let pc = cpu.pc();
+        let in_range = self
+            .pc_filter
+            .as_ref()
+            .map_or(true, |range| range.contains(&pc));
+
+        if in_range && self.depth > 0 {
+            if self.entries.len() == self.depth {
+                self.entries.pop_front();
+            }
+            self.entries.push_back(TraceEntry::capture(cpu));
+        }
+
+        cpu.step()
+    }
+
+    /// Recorded entries, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &TraceEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns the number of recorded entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Discard all recorded entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
@@ -0,0 +1,129 @@
+//! Hung CPU detection.
+//!
+//! A frozen screen is a poor error report. [`Watchdog`] steps the CPU on the
+//! caller's behalf and returns a [`HangReport`] with the recent execution
+//! history as soon as the CPU looks stuck, so a frontend can offer to reset or
+//! break into a debugger.
+
+use crate::{ExecutionError, MemoryBus, CPU};
+use std::collections::VecDeque;
+
+/// Why the watchdog considers the CPU hung.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HangReason {
+    /// An instruction jumped or branched to itself with interrupts disabled.
+    ///
+    /// With the I flag set no IRQ can break the loop, but an NMI still can,
+    /// whether raised by a device or by `CPU::trigger_nmi`. Machines that
+    /// park the CPU in `SEI; JMP *` and wait for an NMI will trigger this
+    /// report, and stepping on from it is safe.
+    SelfLoopWithInterruptsDisabled,
+
+    /// The CPU could not execute the instruction at `pc`.
+    ExecutionFailed(ExecutionError),
+}
+
+/// Diagnostics captured when the watchdog fires.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HangReport {
+    /// Why the CPU is considered hung
+    pub reason: HangReason,
+
+    /// Address of the offending instruction
+    pub pc: u16,
+
+    /// CPU cycle count when the hang was detected
+    pub cycles: u64,
+
+    /// Addresses of the most recently executed instructions, oldest first.
+    ///
+    /// The offending instruction is the last entry.
+    pub recent_pcs: Vec<u16>,
+}
+
+/// Steps the CPU and reports when it is stuck.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::{CPU, FlatMemory, MemoryBus};
+/// use lib6502::debugger::{HangReason, Watchdog};
+///
+/// let mut mem = FlatMemory::new();
+/// mem.write(0xFFFC, 0x00);
+/// mem.write(0xFFFD, 0x80);
+///
+/// // $8000: NOP
+/// // $8001: JMP $8001
+/// mem.write(0x8000, 0xEA);
+/// mem.write(0x8001, 0x4C);
+/// mem.write(0x8002, 0x01);
+/// mem.write(0x8003, 0x80);
+///
+/// let mut cpu = CPU::new(mem); // I flag is set after reset
+/// let mut watchdog = Watchdog::new(16);
+///
+/// assert!(watchdog.step(&mut cpu).is_none()); // NOP
+///
+/// let report = watchdog.step(&mut cpu).unwrap(); // JMP to itself
+/// assert_eq!(report.reason, HangReason::SelfLoopWithInterruptsDisabled);
+/// assert_eq!(report.pc, 0x8001);
+/// assert_eq!(report.recent_pcs, vec![0x8000, 0x8001]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Watchdog {
+    history: VecDeque<u16>,
+    history_depth: usize,
+}
+
+impl Watchdog {
+    /// Create a watchdog that remembers the last `history_depth` instructions.
+    pub fn new(history_depth: usize) -> Self {
+        Self {
+            history: VecDeque::with_capacity(history_depth),
+            history_depth,
+        }
+    }
+
+    /// Execute one instruction and check whether the CPU is now stuck.
+    ///
+    /// # Returns
+    ///
+    /// - `None` if the instruction executed and the CPU can still make progress
+    /// - `Some(HangReport)` if the instruction failed or looped on itself
+    ///   with interrupts disabled (see
+    ///   [`HangReason::SelfLoopWithInterruptsDisabled`] for the NMI caveat)
+    pub fn step<M: MemoryBus>(&mut self, cpu: &mut CPU<M>) -> Option<HangReport> {
+        let pc = cpu.pc();
+        self.record(pc);
+
+        let reason = match cpu.step() {
+            Err(error) => HangReason::ExecutionFailed(error),
+            Ok(()) if cpu.pc() == pc && cpu.flag_i() => HangReason::SelfLoopWithInterruptsDisabled,
+            Ok(()) => return None,
+        };
+
+        Some(HangReport {
+            reason,
+            pc,
+            cycles: cpu.cycles(),
+            recent_pcs: self.history.iter().copied().collect(),
+        })
+    }
+
+    /// Forget the recorded execution history.
+    pub fn clear(&mut self) {
+        self.history.clear();
+    }
+
+    /// Append `pc` to the history, dropping the oldest entry when full.
+    fn record(&mut self, pc: u16) {
+        if self.history_depth == 0 {
+            return;
+        }
+        if self.history.len() == self.history_depth {
+            self.history.pop_front();
+        }
+        self.history.push_back(pc);
+    }
+}
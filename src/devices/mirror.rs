@@ -0,0 +1,171 @@
+//! Address mirroring wrapper.
+//!
+//! Many peripheral chips only decode a handful of address lines, so their small
+//! register file repeats ("mirrors") across a much larger window of the memory
+//! map. `MirroredDevice` expresses that declaratively: the wrapped device keeps
+//! reporting its real register count, and the wrapper claims the full window.
+
+use super::Device;
+use std::any::Any;
+
+/// Wraps a device so its registers repeat across a larger address window.
+///
+/// The wrapper reports `window` as its size to the memory mapper and folds each
+/// incoming offset back into the inner device's range (`offset % inner.size()`).
+/// For power-of-two register files this is the same as masking off the
+/// undecoded address lines.
+///
+/// # Examples
+///
+/// ```rust
+/// use lib6502::{Device, MappedMemory, MemoryBus, MirroredDevice, RamDevice};
+///
+/// // A 64-byte register file that only decodes A0-A5, mirrored across 1KB
+/// let registers = MirroredDevice::new(RamDevice::new(64), 0x0400);
+/// assert_eq!(registers.size(), 0x0400);
+///
+/// let mut memory = MappedMemory::new();
+/// memory.add_device(0xD000, Box::new(registers)).unwrap();
+///
+/// memory.write(0xD020, 0x0E);
+/// assert_eq!(memory.read(0xD060), 0x0E); // Same register, next mirror
+/// assert_eq!(memory.read(0xD3E0), 0x0E); // Last mirror in the window
+/// ```
+pub struct MirroredDevice<D: Device> {
+    inner: D,
+    window: u16,
+}
+
+impl<D: Device> MirroredDevice<D> {
+    /// Create a mirrored view of `device` spanning `window` bytes.
+    ///
+    /// `window` is normally a multiple of `device.size()`. If it is not, the
+    /// final partial mirror simply exposes the first registers of the device.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - Device whose registers should be mirrored
+    /// * `window` - Total number of bytes the mirrored device occupies
+    ///
+    /// # Returns
+    ///
+    /// A new `MirroredDevice` instance
+    pub fn new(device: D, window: u16) -> Self {
+        Self {
+            inner: device,
+            window,
+        }
+    }
+
+    /// Returns a reference to the wrapped device.
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped device.
+    pub fn inner_mut(&mut self) -> &mut D {
+        &mut self.inner
+    }
+
+    /// Consumes the wrapper and returns the wrapped device.
+    pub fn into_inner(self) -> D {
+        self.inner
+    }
+
+    /// Translate an offset within the mirror window to an inner device offset.
+    ///
+    /// Returns `None` if the inner device has no addressable registers.
+    #[inline]
+    fn fold(&self, offset: u16) -> Option<u16> {
+        match self.inner.size() {
+            0 => None,
+            size => Some(offset % size),
+        }
+    }
+}
+
+impl<D: Device + 'static> Device for MirroredDevice<D> {
+    fn read(&self, offset: u16) -> u8 {
+        match self.fold(offset) {
+            Some(inner_offset) => self.inner.read(inner_offset),
+            None => 0xFF, // Nothing decoded: behave like an unmapped read
+        }
+    }
+
+    fn peek(&self, offset: u16) -> u8 {
+        match self.fold(offset) {
+            Some(inner_offset) => self.inner.peek(inner_offset),
+            None => 0xFF,
+        }
+    }
+
+    fn write(&mut self, offset: u16, value: u8) {
+        if let Some(inner_offset) = self.fold(offset) {
+            self.inner.write(inner_offset, value);
+        }
+    }
+
+    fn size(&self) -> u16 {
+        self.window
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn has_interrupt(&self) -> bool {
+        self.inner.has_interrupt()
+    }
+
+    fn has_nmi(&self) -> bool {
+        self.inner.has_nmi()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::devices::{RamDevice, RomDevice};
+
+    #[test]
+    fn test_mirrored_size_reports_window() {
+        let mirrored = MirroredDevice::new(RamDevice::new(32), 0x0400);
+
+        assert_eq!(mirrored.size(), 0x0400);
+        assert_eq!(mirrored.inner().size(), 32);
+    }
+
+    #[test]
+    fn test_mirrored_reads_and_writes_fold() {
+        let mut mirrored = MirroredDevice::new(RamDevice::new(32), 0x0400);
+
+        mirrored.write(0x0005, 0x42);
+        assert_eq!(mirrored.read(0x0025), 0x42);
+        assert_eq!(mirrored.read(0x03E5), 0x42);
+
+        // Writes through a mirror land on the same register
+        mirrored.write(0x0205, 0x99);
+        assert_eq!(mirrored.inner().read(0x05), 0x99);
+    }
+
+    #[test]
+    fn test_mirrored_partial_final_mirror() {
+        let rom = RomDevice::new(vec![0x10, 0x11, 0x12]);
+        let mirrored = MirroredDevice::new(rom, 8);
+
+        assert_eq!(mirrored.read(6), 0x10);
+        assert_eq!(mirrored.read(7), 0x11);
+    }
+
+    #[test]
+    fn test_mirrored_empty_device_reads_unmapped() {
+        let mut mirrored = MirroredDevice::new(RomDevice::new(Vec::new()), 16);
+
+        mirrored.write(3, 0x55);
+        assert_eq!(mirrored.read(3), 0xFF);
+    }
+}
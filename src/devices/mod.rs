@@ -7,7 +7,8 @@
 //!
 //! - **Device trait**: Abstract interface for memory-mapped hardware components
 //! - **MappedMemory**: Routes read/write operations to registered devices based on address ranges
-//! - **Device implementations**: RAM, ROM, UART (6551 ACIA), and future expansion
+//! - **Device implementations**: RAM, ROM, UART (6551 ACIA), real-time clock, and future expansion
+//! - **MirroredDevice**: Repeats a small register file across a larger address window
 //!
 //! # Example
 //!
@@ -34,13 +35,17 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 // Device implementations
+pub mod mirror;
 pub mod ram;
 pub mod rom;
+pub mod rtc;
 pub mod uart;
 
 // Re-export device types
+pub use mirror::MirroredDevice;
 pub use ram::RamDevice;
 pub use rom::RomDevice;
+pub use rtc::RtcDevice;
 pub use uart::Uart6551;
 
 /// Abstract interface for memory-mapped hardware devices.
@@ -100,6 +105,20 @@ pub trait Device {
     /// The byte value at the specified offset
     fn read(&self, offset: u16) -> u8;
 
+    /// Read byte from device without side effects.
+    ///
+    /// Used by debuggers and memory viewers through `MemoryBus::peek`.
+    /// Returns what `read` would return, without consuming buffered data or
+    /// acknowledging interrupts.
+    ///
+    /// # Default Implementation
+    ///
+    /// Calls `read`, which is correct for devices with side-effect-free reads
+    /// (RAM, ROM). Devices whose reads change state must override this.
+    fn peek(&self, offset: u16) -> u8 {
+        self.read(offset)
+    }
+
     /// Write byte to device at offset relative to device base address.
     ///
     /// # Arguments
@@ -110,6 +129,10 @@ pub trait Device {
 
     /// Return size of device's address space in bytes.
     ///
+    /// This should be the number of registers the device actually decodes.
+    /// Wrap the device in [`MirroredDevice`] when it must repeat across a
+    /// larger window of the memory map.
+    ///
     /// # Returns
     ///
     /// Number of bytes in device's address range
@@ -179,6 +202,19 @@ pub trait Device {
     fn has_interrupt(&self) -> bool {
         false // Default: device doesn't support interrupts
     }
+
+    /// Check if device is asserting the NMI line.
+    ///
+    /// Returns the current level of the device's NMI output. The CPU detects
+    /// the inactive-to-active edge, so the device may keep returning `true`
+    /// until its NMI source is acknowledged without triggering repeated NMIs.
+    ///
+    /// # Default Implementation
+    ///
+    /// Returns `false` for devices that are not wired to NMI.
+    fn has_nmi(&self) -> bool {
+        false // Default: device not wired to NMI
+    }
 }
 
 /// Helper for address range calculations and overlap detection.
@@ -257,6 +293,14 @@ impl DeviceHolder {
         }
     }
 
+    /// Read from the held device without side effects.
+    fn peek(&self, offset: u16) -> u8 {
+        match self {
+            DeviceHolder::Owned(device) => device.peek(offset),
+            DeviceHolder::Shared(device) => device.borrow().peek(offset),
+        }
+    }
+
     /// Check if the held device has a pending interrupt.
     fn has_interrupt(&self) -> bool {
         match self {
@@ -264,6 +308,14 @@ impl DeviceHolder {
             DeviceHolder::Shared(device) => device.borrow().has_interrupt(),
         }
     }
+
+    /// Check if the held device is asserting NMI.
+    fn has_nmi(&self) -> bool {
+        match self {
+            DeviceHolder::Owned(device) => device.has_nmi(),
+            DeviceHolder::Shared(device) => device.borrow().has_nmi(),
+        }
+    }
 }
 
 /// Internal mapping of a device to a base address.
@@ -668,6 +720,15 @@ impl MemoryBus for MappedMemory {
         self.unmapped_value
     }
 
+    fn peek(&self, addr: u16) -> u8 {
+        for mapping in &self.devices {
+            if mapping.range().contains(addr) {
+                return mapping.device.peek(addr - mapping.base_addr);
+            }
+        }
+        self.unmapped_value
+    }
+
     fn write(&mut self, addr: u16, value: u8) {
         for mapping in &mut self.devices {
             let range = mapping.range();
@@ -737,6 +798,11 @@ impl MemoryBus for MappedMemory {
             .iter()
             .any(|mapping| mapping.device.has_interrupt())
     }
+
+    fn nmi_active(&self) -> bool {
+        // The NMI line is wired-OR like IRQ; the CPU handles edge detection
+        self.devices.iter().any(|mapping| mapping.device.has_nmi())
+    }
 }
 
 #[cfg(test)]
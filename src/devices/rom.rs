@@ -4,12 +4,18 @@
 
 use super::Device;
 use std::any::Any;
+use std::sync::Arc;
 
 /// Read-only memory device.
 ///
 /// `RomDevice` stores immutable data that can be read but not written.
 /// Writes are silently ignored (no-op), matching typical ROM hardware behavior.
 ///
+/// The contents are held in a shared, immutable `Arc<[u8]>` buffer, so
+/// cloning a `RomDevice` or building several from [`RomDevice::from_shared`]
+/// does not copy the image. This lets many emulator instances share one
+/// copy of a large ROM set.
+///
 /// # Examples
 ///
 /// ```rust
@@ -27,7 +33,7 @@ use std::any::Any;
 /// ```
 #[derive(Clone)]
 pub struct RomDevice {
-    data: Vec<u8>,
+    data: Arc<[u8]>,
 }
 
 impl RomDevice {
@@ -58,8 +64,39 @@ impl RomDevice {
     /// let rom = RomDevice::new(rom_data);
     /// ```
     pub fn new(data: Vec<u8>) -> Self {
+        Self { data: data.into() }
+    }
+
+    /// Create a ROM device backed by an existing shared buffer.
+    ///
+    /// The buffer is not copied, so any number of devices (including devices
+    /// in different CPU instances) can map the same ROM image.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lib6502::{Device, RomDevice};
+    /// use std::sync::Arc;
+    ///
+    /// let kernal: Arc<[u8]> = vec![0xEA; 8192].into();
+    ///
+    /// let rom_a = RomDevice::from_shared(Arc::clone(&kernal));
+    /// let rom_b = RomDevice::from_shared(Arc::clone(&kernal));
+    ///
+    /// assert_eq!(rom_a.read(0), rom_b.read(0));
+    /// assert_eq!(Arc::strong_count(&kernal), 3);
+    /// ```
+    pub fn from_shared(data: Arc<[u8]>) -> Self {
         Self { data }
     }
+
+    /// Returns the shared buffer backing this ROM.
+    ///
+    /// Clone the returned `Arc` to map the same image elsewhere without
+    /// copying it.
+    pub fn shared_data(&self) -> &Arc<[u8]> {
+        &self.data
+    }
 }
 
 impl Device for RomDevice {
@@ -136,4 +173,17 @@ mod tests {
         assert_eq!(rom.read(0x3FFC), 0x00);
         assert_eq!(rom.read(0x3FFD), 0xC0);
     }
+
+    #[test]
+    fn test_rom_shared_buffer_is_not_copied() {
+        let data: Arc<[u8]> = vec![0x01, 0x02, 0x03].into();
+
+        let rom = RomDevice::from_shared(Arc::clone(&data));
+        let cloned = rom.clone();
+
+        assert!(Arc::ptr_eq(rom.shared_data(), &data));
+        assert!(Arc::ptr_eq(cloned.shared_data(), &data));
+        assert_eq!(cloned.read(2), 0x03);
+        assert_eq!(cloned.size(), 3);
+    }
 }
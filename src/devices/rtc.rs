@@ -0,0 +1,218 @@
+//! Real-time clock device.
+//!
+//! Exposes a date and time to the guest as BCD registers, in the style of
+//! cartridge and interface RTCs. The device never reads the host clock
+//! itself: the embedder sets the time, so a recording that starts from a fixed
+//! epoch replays identically.
+
+use super::Device;
+use std::any::Any;
+
+/// Seconds in one day.
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Memory-mapped real-time clock.
+///
+/// ## Register Map (offsets from device base address)
+///
+/// | Offset | Register     | Access | Description |
+/// |--------|--------------|--------|-------------|
+/// | 0      | Seconds      | R      | 00-59 (BCD) |
+/// | 1      | Minutes      | R      | 00-59 (BCD) |
+/// | 2      | Hours        | R      | 00-23 (BCD) |
+/// | 3      | Day of week  | R      | 0-6, 0 = Sunday |
+/// | 4      | Day of month | R      | 01-31 (BCD) |
+/// | 5      | Month        | R      | 01-12 (BCD) |
+/// | 6      | Year         | R      | 00-99 (BCD) |
+/// | 7      | Century      | R      | e.g. 20 (BCD) |
+/// | 8      | Latch        | W      | Any write latches the current time |
+///
+/// Reads return the time captured by the last latch, so a program can read
+/// all fields without them rolling over in between. The time is latched once
+/// on creation; the guest writes the latch register before each read.
+///
+/// # Example
+///
+/// ```rust
+/// use lib6502::{Device, RtcDevice};
+///
+/// // 2024-02-29 13:45:30 UTC (a Thursday)
+/// let mut rtc = RtcDevice::new(1_709_214_330);
+///
+/// assert_eq!(rtc.read(2), 0x13); // Hours
+/// assert_eq!(rtc.read(4), 0x29); // Day
+/// assert_eq!(rtc.read(5), 0x02); // Month
+/// assert_eq!(rtc.read(6), 0x24); // Year
+/// assert_eq!(rtc.read(3), 4);    // Thursday
+///
+/// // Time moves only when the host says so, and the guest sees it after a latch
+/// rtc.advance(30);
+/// assert_eq!(rtc.read(1), 0x45);
+/// rtc.write(8, 0);
+/// assert_eq!(rtc.read(1), 0x46);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RtcDevice {
+    /// Current time in seconds since 1970-01-01 00:00:00 UTC
+    unix_time: u64,
+
+    /// Register values captured by the last latch
+    latched: [u8; 8],
+}
+
+impl RtcDevice {
+    /// Number of registers (latched time fields plus the latch register)
+    const SIZE: u16 = 9;
+
+    /// Latch register offset
+    const LATCH_REG: u16 = 8;
+
+    /// Create a clock set to `unix_time` seconds since the Unix epoch (UTC).
+    ///
+    /// Pass a fixed value for deterministic runs, or the host time (for
+    /// example from `std::time::SystemTime`) for a live clock.
+    pub fn new(unix_time: u64) -> Self {
+        let mut rtc = Self {
+            unix_time,
+            latched: [0; 8],
+        };
+        rtc.latch();
+        rtc
+    }
+
+    /// Returns the current time in seconds since the Unix epoch.
+    pub fn unix_time(&self) -> u64 {
+        self.unix_time
+    }
+
+    /// Set the current time. The guest sees it after its next latch.
+    pub fn set_unix_time(&mut self, unix_time: u64) {
+        self.unix_time = unix_time;
+    }
+
+    /// Move the clock forward by `seconds`.
+    pub fn advance(&mut self, seconds: u64) {
+        self.unix_time = self.unix_time.saturating_add(seconds);
+    }
+
+    /// Capture the current time into the readable registers.
+    fn latch(&mut self) {
+        let days = self.unix_time / SECONDS_PER_DAY;
+        let time_of_day = self.unix_time % SECONDS_PER_DAY;
+        let (year, month, day) = civil_from_days(days);
+
+        // 1970-01-01 was a Thursday
+        let weekday = ((days + 4) % 7) as u8;
+
+        self.latched = [
+            to_bcd((time_of_day % 60) as u8),
+            to_bcd((time_of_day / 60 % 60) as u8),
+            to_bcd((time_of_day / 3600) as u8),
+            weekday,
+            to_bcd(day),
+            to_bcd(month),
+            to_bcd((year % 100) as u8),
+            to_bcd((year / 100 % 100) as u8),
+        ];
+    }
+}
+
+impl Device for RtcDevice {
+    fn read(&self, offset: u16) -> u8 {
+        self.latched.get(offset as usize).copied().unwrap_or(0x00)
+    }
+
+    fn write(&mut self, offset: u16, _value: u8) {
+        if offset == Self::LATCH_REG {
+            self.latch();
+        }
+    }
+
+    fn size(&self) -> u16 {
+        Self::SIZE
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Convert a two-digit value (0-99) to packed BCD.
+fn to_bcd(value: u8) -> u8 {
+    ((value / 10) << 4) | (value % 10)
+}
+
+/// Convert days since 1970-01-01 to a (year, month, day) Gregorian date.
+///
+/// Uses Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(days: u64) -> (u64, u8, u8) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_epoch() {
+        let rtc = RtcDevice::new(0);
+
+        assert_eq!(rtc.read(0), 0x00);
+        assert_eq!(rtc.read(2), 0x00);
+        assert_eq!(rtc.read(3), 4); // Thursday
+        assert_eq!(rtc.read(4), 0x01);
+        assert_eq!(rtc.read(5), 0x01);
+        assert_eq!(rtc.read(6), 0x70);
+        assert_eq!(rtc.read(7), 0x19);
+    }
+
+    #[test]
+    fn test_year_rollover() {
+        // 1999-12-31 23:59:59
+        let mut rtc = RtcDevice::new(946_684_799);
+        assert_eq!(rtc.read(0), 0x59);
+        assert_eq!(rtc.read(6), 0x99);
+        assert_eq!(rtc.read(7), 0x19);
+
+        rtc.advance(1);
+        rtc.write(RtcDevice::LATCH_REG, 0);
+        assert_eq!(rtc.read(0), 0x00);
+        assert_eq!(rtc.read(4), 0x01);
+        assert_eq!(rtc.read(5), 0x01);
+        assert_eq!(rtc.read(6), 0x00);
+        assert_eq!(rtc.read(7), 0x20);
+        assert_eq!(rtc.read(3), 6); // Saturday
+    }
+
+    #[test]
+    fn test_reads_hold_until_latched() {
+        let mut rtc = RtcDevice::new(0);
+
+        rtc.set_unix_time(3_661);
+        assert_eq!(rtc.read(0), 0x00);
+
+        // Writes to the time registers do not latch
+        rtc.write(0, 0xFF);
+        assert_eq!(rtc.read(0), 0x00);
+
+        rtc.write(RtcDevice::LATCH_REG, 0);
+        assert_eq!(rtc.read(0), 0x01);
+        assert_eq!(rtc.read(1), 0x01);
+        assert_eq!(rtc.read(2), 0x01);
+        assert_eq!(rtc.read(RtcDevice::LATCH_REG), 0x00);
+    }
+}
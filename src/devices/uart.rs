@@ -351,6 +351,19 @@ impl Device for Uart6551 {
         }
     }
 
+    fn peek(&self, offset: u16) -> u8 {
+        match offset {
+            // Data register: show the next received byte without popping it
+            0 => self
+                .rx_buffer
+                .borrow()
+                .front()
+                .copied()
+                .unwrap_or(*self.last_rx_byte.borrow()),
+            _ => self.read(offset),
+        }
+    }
+
     fn write(&mut self, offset: u16, value: u8) {
         match offset {
             0 => self.write_data_register(value),
@@ -443,6 +456,25 @@ mod tests {
         assert_eq!(uart.read(0), 0x41);
     }
 
+    #[test]
+    fn test_uart_peek_does_not_consume() {
+        let mut uart = Uart6551::new();
+        uart.receive_byte(0x41);
+        uart.receive_byte(0x42);
+
+        // Peeking shows the next byte but leaves the buffer alone
+        assert_eq!(uart.peek(0), 0x41);
+        assert_eq!(uart.peek(0), 0x41);
+        assert_eq!(uart.rx_buffer_len(), 2);
+
+        assert_eq!(uart.read(0), 0x41);
+        assert_eq!(uart.read(0), 0x42);
+
+        // Empty buffer: peek shows the last byte read, like read does
+        assert_eq!(uart.peek(0), 0x42);
+        assert_eq!(uart.peek(1), uart.status());
+    }
+
     #[test]
     fn test_uart_status_register_read_only() {
         let mut uart = Uart6551::new();
@@ -1,7 +1,7 @@
 //! Instruction decoder for the 6502 disassembler
 
 use crate::disassembler::Instruction;
-use crate::opcodes::OPCODE_TABLE;
+use crate::opcodes::{has_canonical_encoding, OPCODE_TABLE};
 
 /// Decode a single instruction from a byte slice
 ///
@@ -12,7 +12,10 @@ use crate::opcodes::OPCODE_TABLE;
 ///
 /// # Returns
 ///
-/// Some(Instruction) if the opcode is valid, None for illegal opcodes
+/// Some(Instruction) if the opcode is valid, None for JAM opcodes or truncated input.
+/// Undocumented opcodes such as LAX decode with their conventional mnemonic;
+/// None is also returned for undocumented duplicates that would not
+/// reassemble to the same byte (see `has_canonical_encoding`).
 pub fn decode_instruction(bytes: &[u8], address: u16) -> Option<Instruction> {
     if bytes.is_empty() {
         return None;
@@ -21,11 +24,17 @@ pub fn decode_instruction(bytes: &[u8], address: u16) -> Option<Instruction> {
     let opcode = bytes[0];
     let metadata = &OPCODE_TABLE[opcode as usize];
 
-    // Check if this is a valid opcode (illegal opcodes have "???" as mnemonic)
+    // Check if this is a valid opcode (JAM opcodes have "???" as mnemonic)
     if metadata.mnemonic == "???" {
         return None;
     }
 
+    // Illegal duplicates (e.g. NOP $54, SBC $EB) would reassemble to a
+    // different opcode, so they are left for the caller to emit as .byte
+    if !has_canonical_encoding(opcode) {
+        return None;
+    }
+
     // Check if there are enough bytes for the full instruction
     if bytes.len() < metadata.size_bytes as usize {
         return None;
@@ -31,74 +31,7 @@ pub(crate) fn execute_adc<M: MemoryBus>(
     // Get the operand value and check for page crossing
     let (value, page_crossed) = cpu.get_operand_value(metadata.addressing_mode)?;
 
-    // Perform the ADC operation
-    let a = cpu.a;
-    let carry_in = if cpu.flag_c { 1 } else { 0 };
-
-    let result: u8;
-
-    if cpu.flag_d {
-        // BCD (Binary Coded Decimal) mode
-        // Each nibble represents a decimal digit (0-9)
-        // Need to adjust when nibbles exceed 9
-
-        // Add low nibbles (ones digit)
-        let mut al = (a & 0x0F) + (value & 0x0F) + carry_in;
-        if al >= 0x0A {
-            // Adjust low nibble if >= 10
-            al = ((al + 0x06) & 0x0F) + 0x10;
-        }
-
-        // Add high nibbles (tens digit) plus any carry from low nibble
-        let mut ah = (a >> 4) + (value >> 4) + (al >> 4);
-
-        // Set carry flag if high nibble >= 10
-        if ah >= 0x0A {
-            ah = (ah + 0x06) & 0x0F;
-            cpu.flag_c = true;
-        } else {
-            cpu.flag_c = false;
-        }
-
-        // Combine nibbles into result
-        result = (ah << 4) | (al & 0x0F);
-
-        // Zero flag: Set if result is 0
-        cpu.flag_z = result == 0;
-
-        // Note: N and V flags are undefined in decimal mode on NMOS 6502
-        // We leave them unchanged (some implementations set them from binary result)
-        // The Klaus test may not rely on specific N/V behavior in decimal mode
-    } else {
-        // Binary mode (standard two's complement addition)
-
-        // Perform addition with carry
-        let result16 = a as u16 + value as u16 + carry_in as u16;
-        result = result16 as u8;
-
-        // Update flags
-
-        // Carry flag: Set if result > 255
-        cpu.flag_c = result16 > 0xFF;
-
-        // Zero flag: Set if result is 0
-        cpu.flag_z = result == 0;
-
-        // Negative flag: Set if bit 7 of result is set
-        cpu.flag_n = (result & 0x80) != 0;
-
-        // Overflow flag: Set if sign bit is incorrect
-        // Overflow occurs when:
-        // - Adding two positive numbers yields a negative result, or
-        // - Adding two negative numbers yields a positive result
-        // Formula: V = (A^result) & (M^result) & 0x80
-        // This checks if both operands had same sign but result has different sign
-        let overflow = ((a ^ result) & (value ^ result) & 0x80) != 0;
-        cpu.flag_v = overflow;
-    }
-
-    // Store result in accumulator
-    cpu.a = result;
+    add_with_carry(cpu, value);
 
     // Update cycle count (add extra cycle for page crossing if applicable)
     let mut cycles = metadata.base_cycles as u64;
@@ -474,87 +407,170 @@ pub(crate) fn execute_sbc<M: MemoryBus>(
     // Get the operand value and check for page crossing
     let (value, page_crossed) = cpu.get_operand_value(metadata.addressing_mode)?;
 
-    // Perform the SBC operation
-    // SBC is: A = A - M - (1 - C) = A + ~M + C
-    let a = cpu.a;
-    let carry_in = if cpu.flag_c { 1 } else { 0 };
-
-    let result: u8;
+    subtract_with_carry(cpu, value);
 
-    if cpu.flag_d {
-        // BCD (Binary Coded Decimal) mode
-        // Each nibble represents a decimal digit (0-9)
-        // Need to adjust when borrowing occurs
+    // Update cycle count (add extra cycle for page crossing if applicable)
+    let mut cycles = metadata.base_cycles as u64;
+    if page_crossed {
+        cycles += 1;
+    }
+    cpu.cycles += cycles;
 
-        let borrow_in = 1 - carry_in; // SBC uses inverted carry as borrow
+    // Advance PC
+    cpu.pc = cpu.pc.wrapping_add(metadata.size_bytes as u16);
 
-        // Subtract low nibbles (ones digit)
-        let mut al = (a & 0x0F) as i16 - (value & 0x0F) as i16 - borrow_in as i16;
-        if al < 0 {
-            al -= 6; // Adjust for decimal borrow (difference between binary and decimal)
-        }
+    Ok(())
+}
 
-        // Subtract high nibbles (tens digit)
-        let mut ah = (a >> 4) as i16 - (value >> 4) as i16;
-        if al < 0 {
-            ah -= 1; // Borrow from high nibble
-        }
-        if ah < 0 {
-            ah -= 6; // Adjust for decimal borrow
-        }
+/// Adds `value` plus the carry flag to the accumulator, updating C, Z, N and V.
+///
+/// Shared by ADC and the illegal RRA opcode. Honors decimal mode.
+pub(crate) fn add_with_carry<M: MemoryBus>(cpu: &mut CPU<M>, value: u8) {
+    // Perform the ADC operation
+    let a = cpu.a;
+    let carry_in = if cpu.flag_c { 1 } else { 0 };
 
-        // Set carry flag: true if no borrow, false if borrow
-        cpu.flag_c = ah >= 0;
+    // Binary sum: the result in binary mode, and the source of the Z flag in
+    // both modes (NMOS quirk)
+    let result16 = a as u16 + value as u16 + carry_in as u16;
+    let binary_result = result16 as u8;
 
-        // Combine nibbles into result
-        result = (((ah & 0x0F) << 4) | (al & 0x0F)) as u8;
+    // Zero flag: Set if the binary result is 0
+    cpu.flag_z = binary_result == 0;
 
-        // Zero flag: Set if result is 0
-        cpu.flag_z = result == 0;
+    let result = if cpu.flag_d && cpu.decimal_mode_enabled {
+        // BCD (Binary Coded Decimal) mode, NMOS behavior
+        // Each nibble represents a decimal digit (0-9)
+        let (decimal_result, carry, negative, overflow) = decimal_adc(a, value, carry_in);
 
-        // Note: N and V flags are undefined in decimal mode on NMOS 6502
-        // We leave them unchanged
-    } else {
-        // Binary mode (standard two's complement subtraction)
+        cpu.flag_c = carry;
 
-        // Perform subtraction using two's complement: A - M - (1 - C) = A + ~M + C
-        let result16 = a as u16 + (!value) as u16 + carry_in as u16;
-        result = result16 as u8;
+        // N and V are "undocumented" in decimal mode, but the NMOS 6502
+        // derives them from the intermediate result before the high nibble
+        // is decimal-adjusted
+        cpu.flag_n = negative;
+        cpu.flag_v = overflow;
 
-        // Update flags
+        decimal_result
+    } else {
+        // Binary mode (standard two's complement addition)
 
-        // Carry flag: Set if no borrow occurred (result >= 0 in signed terms)
-        // In subtraction, carry is set when result16 > 0xFF (no borrow needed)
+        // Carry flag: Set if result > 255
         cpu.flag_c = result16 > 0xFF;
 
-        // Zero flag: Set if result is 0
-        cpu.flag_z = result == 0;
-
         // Negative flag: Set if bit 7 of result is set
-        cpu.flag_n = (result & 0x80) != 0;
+        cpu.flag_n = (binary_result & 0x80) != 0;
 
         // Overflow flag: Set if sign bit is incorrect
         // Overflow occurs when:
-        // - Subtracting a positive from a negative yields a positive, or
-        // - Subtracting a negative from a positive yields a negative
-        // Formula: V = (A^result) & (A^M) & 0x80
-        // This checks if A and M had different signs and result has different sign from A
-        let overflow = ((a ^ result) & (a ^ value) & 0x80) != 0;
+        // - Adding two positive numbers yields a negative result, or
+        // - Adding two negative numbers yields a positive result
+        // Formula: V = (A^result) & (M^result) & 0x80
+        // This checks if both operands had same sign but result has different sign
+        let overflow = ((a ^ binary_result) & (value ^ binary_result) & 0x80) != 0;
         cpu.flag_v = overflow;
-    }
+
+        binary_result
+    };
 
     // Store result in accumulator
     cpu.a = result;
+}
 
-    // Update cycle count (add extra cycle for page crossing if applicable)
-    let mut cycles = metadata.base_cycles as u64;
-    if page_crossed {
-        cycles += 1;
+/// Subtracts `value` and the borrow (NOT carry) from the accumulator,
+/// updating C, Z, N and V.
+///
+/// Shared by SBC and the illegal ISC opcode. Honors decimal mode.
+pub(crate) fn subtract_with_carry<M: MemoryBus>(cpu: &mut CPU<M>, value: u8) {
+    // Perform the SBC operation
+    // SBC is: A = A - M - (1 - C) = A + ~M + C
+    let a = cpu.a;
+    let carry_in = if cpu.flag_c { 1 } else { 0 };
+
+    // Binary difference using two's complement: A - M - (1 - C) = A + ~M + C
+    // On the NMOS 6502 all flags come from this, even in decimal mode
+    let result16 = a as u16 + (!value) as u16 + carry_in as u16;
+    let binary_result = result16 as u8;
+
+    // Update flags
+
+    // Carry flag: Set if no borrow occurred (result >= 0 in signed terms)
+    // In subtraction, carry is set when result16 > 0xFF (no borrow needed)
+    cpu.flag_c = result16 > 0xFF;
+
+    // Zero flag: Set if result is 0
+    cpu.flag_z = binary_result == 0;
+
+    // Negative flag: Set if bit 7 of result is set
+    cpu.flag_n = (binary_result & 0x80) != 0;
+
+    // Overflow flag: Set if sign bit is incorrect
+    // Overflow occurs when:
+    // - Subtracting a positive from a negative yields a positive, or
+    // - Subtracting a negative from a positive yields a negative
+    // Formula: V = (A^result) & (A^M) & 0x80
+    // This checks if A and M had different signs and result has different sign from A
+    let overflow = ((a ^ binary_result) & (a ^ value) & 0x80) != 0;
+    cpu.flag_v = overflow;
+
+    let result = if cpu.flag_d && cpu.decimal_mode_enabled {
+        // BCD (Binary Coded Decimal) mode: only the accumulator differs
+        decimal_sbc(a, value, carry_in)
+    } else {
+        binary_result
+    };
+
+    // Store result in accumulator
+    cpu.a = result;
+}
+
+/// Computes an NMOS decimal mode addition.
+///
+/// Follows the sequence documented in "Decimal Mode" by Bruce Clark
+/// (6502.org tutorial, appendix A), which also defines the results for
+/// invalid BCD operands.
+///
+/// # Returns
+///
+/// A tuple of (result, carry, negative, overflow)
+fn decimal_adc(a: u8, value: u8, carry_in: u8) -> (u8, bool, bool, bool) {
+    // Add low nibbles (ones digit), adjusting if the digit overflows
+    let mut low = (a & 0x0F) as i16 + (value & 0x0F) as i16 + carry_in as i16;
+    if low >= 0x0A {
+        low = ((low + 0x06) & 0x0F) + 0x10;
     }
-    cpu.cycles += cycles;
 
-    // Advance PC
-    cpu.pc = cpu.pc.wrapping_add(metadata.size_bytes as u16);
+    // N and V: intermediate sum with the high nibbles taken as signed values
+    let signed = (a & 0xF0) as i8 as i16 + (value & 0xF0) as i8 as i16 + low;
+    let negative = (signed & 0x80) != 0;
+    let overflow = !(-128..=127).contains(&signed);
 
-    Ok(())
+    // Accumulator and C: unsigned sum with the tens digit adjusted
+    let mut sum = (a & 0xF0) as i16 + (value & 0xF0) as i16 + low;
+    if sum >= 0xA0 {
+        sum += 0x60;
+    }
+
+    (sum as u8, sum >= 0x100, negative, overflow)
+}
+
+/// Computes the accumulator result of an NMOS decimal mode subtraction.
+///
+/// Follows the sequence documented in "Decimal Mode" by Bruce Clark
+/// (6502.org tutorial, appendix A). Flags are not returned because the NMOS
+/// 6502 sets them exactly as in binary mode.
+fn decimal_sbc(a: u8, value: u8, carry_in: u8) -> u8 {
+    // Subtract low nibbles (ones digit), adjusting if a borrow occurs
+    let mut low = (a & 0x0F) as i16 - (value & 0x0F) as i16 + carry_in as i16 - 1;
+    if low < 0 {
+        low = ((low - 0x06) & 0x0F) - 0x10;
+    }
+
+    // Subtract high nibbles (tens digit), adjusting if a borrow occurs
+    let mut difference = (a & 0xF0) as i16 - (value & 0xF0) as i16 + low;
+    if difference < 0 {
+        difference -= 0x60;
+    }
+
+    difference as u8
 }
@@ -12,8 +12,10 @@
 //! 1. Pushes PC+2 to the stack (high byte first, then low byte)
 //! 2. Pushes processor status to stack with B flag set
 //! 3. Sets the I (interrupt disable) flag
-//! 4. Loads PC from IRQ vector at $FFFE/F
+//! 4. Loads PC from IRQ vector at $FFFE/F (or the NMI vector if an NMI
+//!    hijacks the BRK)
 
+use crate::cpu::{IRQ_VECTOR, NMI_VECTOR};
 use crate::{AddressingMode, ExecutionError, MemoryBus, CPU, OPCODE_TABLE};
 
 /// Executes the BRK (Force Interrupt) instruction.
@@ -26,6 +28,10 @@ use crate::{AddressingMode, ExecutionError, MemoryBus, CPU, OPCODE_TABLE};
 /// 5. Setting the I (interrupt disable) flag
 /// 6. Loading the PC from the IRQ vector at addresses $FFFE (low) and $FFFF (high)
 ///
+/// If an NMI edge arrives before the vector fetch, the BRK is hijacked: it
+/// loads the PC from the NMI vector at $FFFA/$FFFB instead, and the NMI is
+/// consumed. The NMI handler sees B set in the pushed status.
+///
 /// Cycle timing: 7 cycles (fixed)
 ///
 /// Flags affected:
@@ -69,9 +75,18 @@ pub(crate) fn execute_brk<M: MemoryBus>(
     // Set the interrupt disable flag
     cpu.flag_i = true;
 
-    // Load PC from IRQ vector at $FFFE/F (little-endian)
-    let pc_low = cpu.memory.read(0xFFFE) as u16;
-    let pc_high = cpu.memory.read(0xFFFF) as u16;
+    // NMI hijacking: a pending NMI redirects the vector fetch
+    cpu.check_nmi_line();
+    let vector = if cpu.nmi_pending {
+        cpu.nmi_pending = false;
+        NMI_VECTOR
+    } else {
+        IRQ_VECTOR
+    };
+
+    // Load PC from the vector (little-endian)
+    let pc_low = cpu.memory.read(vector) as u16;
+    let pc_high = cpu.memory.read(vector.wrapping_add(1)) as u16;
     cpu.pc = (pc_high << 8) | pc_low;
 
     // Update cycle count
@@ -218,12 +233,16 @@ pub(crate) fn execute_jsr<M: MemoryBus>(
 /// Bytes: 1
 /// Cycles: 2
 ///
+/// The illegal NOP variants ($1A, $80, $04, $14, $0C, $1C, ...) use other
+/// addressing modes. They read their operand like a load would, including
+/// the extra cycle when an Absolute,X access crosses a page, and discard it.
+///
 /// Flags affected: None
 ///
 /// # Arguments
 ///
 /// * `cpu` - Mutable reference to the CPU
-/// * `opcode` - The opcode byte for this NOP instruction (0xEA)
+/// * `opcode` - The opcode byte for this NOP instruction
 ///
 /// # Examples
 ///
@@ -249,12 +268,21 @@ pub(crate) fn execute_nop<M: MemoryBus>(
 ) -> Result<(), ExecutionError> {
     let metadata = &OPCODE_TABLE[opcode as usize];
 
+    // Illegal NOPs with an operand still perform the (discarded) read
+    let page_crossed = match metadata.addressing_mode {
+        AddressingMode::Implicit | AddressingMode::Immediate => false,
+        mode => cpu.get_operand_value(mode)?.1,
+    };
+
     // NOP does nothing - just advance PC and add cycles
-    // Advance PC by instruction size (1 byte for implicit addressing)
     cpu.pc = cpu.pc.wrapping_add(metadata.size_bytes as u16);
 
-    // Add cycles (2 cycles for NOP)
-    cpu.cycles += metadata.base_cycles as u64;
+    // Add cycles (2 cycles for NOP, plus page crossing for NOP abs,X)
+    let mut cycles = metadata.base_cycles as u64;
+    if page_crossed {
+        cycles += 1;
+    }
+    cpu.cycles += cycles;
 
     Ok(())
 }
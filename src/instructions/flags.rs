@@ -136,6 +136,9 @@ pub(crate) fn execute_sec<M: MemoryBus>(
 /// - I: Set to 0
 /// - All other flags: Unchanged
 ///
+/// The change takes effect after the IRQ poll, so an IRQ that is already
+/// pending is serviced only after the instruction following CLI.
+///
 /// # Arguments
 ///
 /// * `cpu` - Mutable reference to the CPU
@@ -191,6 +194,9 @@ pub(crate) fn execute_cli<M: MemoryBus>(
 /// - I: Set to 1
 /// - All other flags: Unchanged
 ///
+/// The change takes effect after the IRQ poll, so an IRQ that is already
+/// pending is still serviced immediately after SEI.
+///
 /// # Arguments
 ///
 /// * `cpu` - Mutable reference to the CPU
@@ -0,0 +1,598 @@
+//! # Illegal (Undocumented) Instructions
+//!
+//! This module implements the undocumented NMOS 6502 opcodes that software in
+//! the wild relies on. Most are combinations of two documented operations that
+//! the decode logic happens to enable at the same time:
+//! - SLO: ASL memory, then ORA
+//! - RLA: ROL memory, then AND
+//! - SRE: LSR memory, then EOR
+//! - RRA: ROR memory, then ADC
+//! - DCP: DEC memory, then CMP
+//! - ISC: INC memory, then SBC
+//! - LAX: LDA and LDX
+//! - SAX: Store A AND X
+//! - LAS: Load A, X and SP with memory AND SP
+//! - ANC, ALR, ARR, AXS: Immediate AND combined with a shift or subtract
+//!
+//! A few are "unstable" on real silicon: XAA and LAX #imm depend on an
+//! analog constant, and SHA, SHX, SHY and TAS corrupt their target address
+//! when indexing crosses a page. These use the behavior most emulators and
+//! test suites agree on. The NOP variants and the SBC duplicate ($EB) are
+//! handled by the documented implementations.
+
+use crate::{AddressingMode, ExecutionError, MemoryBus, CPU, OPCODE_TABLE};
+
+/// Constant ORed into A by the unstable XAA and LAX #imm opcodes.
+///
+/// The real value varies between chips and with temperature; $EE is the
+/// value commonly measured on C64 CPUs.
+const UNSTABLE_MAGIC: u8 = 0xEE;
+
+/// Executes the SLO (ASL + ORA) instruction.
+///
+/// Shifts memory left one bit, then ORs the result into the accumulator.
+/// Updates C (from the shift), Z and N (from A).
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this SLO instruction
+pub(crate) fn execute_slo<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    read_modify_write(cpu, opcode, |cpu, value| {
+        cpu.flag_c = (value & 0x80) != 0;
+        let result = value << 1;
+
+        cpu.a |= result;
+        set_zn(cpu, cpu.a);
+
+        result
+    })
+}
+
+/// Executes the RLA (ROL + AND) instruction.
+///
+/// Rotates memory left one bit through carry, then ANDs the result into the
+/// accumulator. Updates C (from the rotate), Z and N (from A).
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this RLA instruction
+pub(crate) fn execute_rla<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    read_modify_write(cpu, opcode, |cpu, value| {
+        let carry_in = cpu.flag_c as u8;
+        cpu.flag_c = (value & 0x80) != 0;
+        let result = (value << 1) | carry_in;
+
+        cpu.a &= result;
+        set_zn(cpu, cpu.a);
+
+        result
+    })
+}
+
+/// Executes the SRE (LSR + EOR) instruction.
+///
+/// Shifts memory right one bit, then EORs the result into the accumulator.
+/// Updates C (from the shift), Z and N (from A).
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this SRE instruction
+pub(crate) fn execute_sre<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    read_modify_write(cpu, opcode, |cpu, value| {
+        cpu.flag_c = (value & 0x01) != 0;
+        let result = value >> 1;
+
+        cpu.a ^= result;
+        set_zn(cpu, cpu.a);
+
+        result
+    })
+}
+
+/// Executes the RRA (ROR + ADC) instruction.
+///
+/// Rotates memory right one bit through carry, then adds the result to the
+/// accumulator using the carry produced by the rotate. Flags are set as by
+/// ADC, including decimal mode.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this RRA instruction
+pub(crate) fn execute_rra<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    read_modify_write(cpu, opcode, |cpu, value| {
+        let carry_in = if cpu.flag_c { 0x80 } else { 0x00 };
+        cpu.flag_c = (value & 0x01) != 0;
+        let result = (value >> 1) | carry_in;
+
+        crate::instructions::alu::add_with_carry(cpu, result);
+
+        result
+    })
+}
+
+/// Executes the DCP (DEC + CMP) instruction.
+///
+/// Decrements memory, then compares the accumulator with the result.
+/// Flags are set as by CMP.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this DCP instruction
+pub(crate) fn execute_dcp<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    read_modify_write(cpu, opcode, |cpu, value| {
+        let result = value.wrapping_sub(1);
+
+        cpu.flag_c = cpu.a >= result;
+        set_zn(cpu, cpu.a.wrapping_sub(result));
+
+        result
+    })
+}
+
+/// Executes the ISC (INC + SBC) instruction.
+///
+/// Increments memory, then subtracts the result from the accumulator.
+/// Flags are set as by SBC, including decimal mode.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this ISC instruction
+pub(crate) fn execute_isc<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    read_modify_write(cpu, opcode, |cpu, value| {
+        let result = value.wrapping_add(1);
+
+        crate::instructions::alu::subtract_with_carry(cpu, result);
+
+        result
+    })
+}
+
+/// Executes the LAX (LDA + LDX) instruction.
+///
+/// Loads memory into both the accumulator and X register. Updates Z and N.
+///
+/// The immediate form ($AB) is unstable on hardware and computes
+/// `(A | $EE) & #imm` instead of loading the operand directly.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this LAX instruction
+pub(crate) fn execute_lax<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    let metadata = &OPCODE_TABLE[opcode as usize];
+
+    // Get the operand value and check for page crossing
+    let (value, page_crossed) = cpu.get_operand_value(metadata.addressing_mode)?;
+
+    let result = if metadata.addressing_mode == AddressingMode::Immediate {
+        (cpu.a | UNSTABLE_MAGIC) & value
+    } else {
+        value
+    };
+
+    cpu.a = result;
+    cpu.x = result;
+    set_zn(cpu, result);
+
+    // Update cycle count (add extra cycle for page crossing if applicable)
+    let mut cycles = metadata.base_cycles as u64;
+    if page_crossed {
+        cycles += 1;
+    }
+    cpu.cycles += cycles;
+
+    // Advance PC
+    cpu.pc = cpu.pc.wrapping_add(metadata.size_bytes as u16);
+
+    Ok(())
+}
+
+/// Executes the SAX (Store A AND X) instruction.
+///
+/// Stores the bitwise AND of the accumulator and X register. No flags are
+/// affected.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this SAX instruction
+pub(crate) fn execute_sax<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    let metadata = &OPCODE_TABLE[opcode as usize];
+
+    let addr = cpu.get_effective_address(metadata.addressing_mode)?;
+    cpu.memory.write(addr, cpu.a & cpu.x);
+
+    // Update cycle count (stores have no page crossing penalty)
+    cpu.cycles += metadata.base_cycles as u64;
+
+    // Advance PC
+    cpu.pc = cpu.pc.wrapping_add(metadata.size_bytes as u16);
+
+    Ok(())
+}
+
+/// Executes the LAS (Load A, X and SP) instruction.
+///
+/// ANDs memory with the stack pointer and stores the result in A, X and SP.
+/// Updates Z and N.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this LAS instruction
+pub(crate) fn execute_las<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    let metadata = &OPCODE_TABLE[opcode as usize];
+
+    // Get the operand value and check for page crossing
+    let (value, page_crossed) = cpu.get_operand_value(metadata.addressing_mode)?;
+
+    let result = value & cpu.sp;
+    cpu.a = result;
+    cpu.x = result;
+    cpu.sp = result;
+    set_zn(cpu, result);
+
+    // Update cycle count (add extra cycle for page crossing if applicable)
+    let mut cycles = metadata.base_cycles as u64;
+    if page_crossed {
+        cycles += 1;
+    }
+    cpu.cycles += cycles;
+
+    // Advance PC
+    cpu.pc = cpu.pc.wrapping_add(metadata.size_bytes as u16);
+
+    Ok(())
+}
+
+/// Executes the ANC (AND + copy N to C) instruction.
+///
+/// ANDs the immediate operand into the accumulator, then copies bit 7 of the
+/// result into the carry flag. Updates C, Z and N.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this ANC instruction
+pub(crate) fn execute_anc<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    immediate(cpu, opcode, |cpu, value| {
+        cpu.a &= value;
+        set_zn(cpu, cpu.a);
+        cpu.flag_c = cpu.flag_n;
+    })
+}
+
+/// Executes the ALR (AND + LSR) instruction.
+///
+/// ANDs the immediate operand into the accumulator, then shifts the
+/// accumulator right one bit. Updates C, Z and N.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this ALR instruction
+pub(crate) fn execute_alr<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    immediate(cpu, opcode, |cpu, value| {
+        let anded = cpu.a & value;
+        cpu.flag_c = (anded & 0x01) != 0;
+        cpu.a = anded >> 1;
+        set_zn(cpu, cpu.a);
+    })
+}
+
+/// Executes the ARR (AND + ROR) instruction.
+///
+/// ANDs the immediate operand into the accumulator, then rotates the
+/// accumulator right one bit through carry. The flags do not follow ROR:
+///
+/// - Carry (C): Bit 6 of the result
+/// - Overflow (V): Bit 6 XOR bit 5 of the result
+/// - Zero (Z) and Negative (N): From the result
+///
+/// In decimal mode the result and carry are BCD-adjusted, following the
+/// NMOS behavior described in the "64doc" reference.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this ARR instruction
+pub(crate) fn execute_arr<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    immediate(cpu, opcode, |cpu, value| {
+        let anded = cpu.a & value;
+        let carry_in = if cpu.flag_c { 0x80 } else { 0x00 };
+        let mut result = (anded >> 1) | carry_in;
+
+        set_zn(cpu, result);
+
+        if cpu.flag_d && cpu.decimal_mode_enabled {
+            // V comes from the un-adjusted rotate, N from the old carry
+            cpu.flag_v = ((anded ^ result) & 0x40) != 0;
+
+            let low = anded & 0x0F;
+            let high = anded >> 4;
+
+            if low + (low & 0x01) > 5 {
+                result = (result & 0xF0) | (result.wrapping_add(6) & 0x0F);
+            }
+
+            cpu.flag_c = high + (high & 0x01) > 5;
+            if cpu.flag_c {
+                result = result.wrapping_add(0x60);
+            }
+        } else {
+            cpu.flag_c = (result & 0x40) != 0;
+            cpu.flag_v = ((result >> 6) ^ (result >> 5)) & 0x01 != 0;
+        }
+
+        cpu.a = result;
+    })
+}
+
+/// Executes the XAA (ANE) instruction.
+///
+/// Unstable on hardware. Computes `(A | $EE) & X & #imm` into the
+/// accumulator. Updates Z and N.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this XAA instruction
+pub(crate) fn execute_xaa<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    immediate(cpu, opcode, |cpu, value| {
+        cpu.a = (cpu.a | UNSTABLE_MAGIC) & cpu.x & value;
+        set_zn(cpu, cpu.a);
+    })
+}
+
+/// Executes the AXS (SBX) instruction.
+///
+/// Stores `(A & X) - #imm` in the X register. The subtraction works like CMP:
+/// it ignores the incoming carry and decimal mode, and sets C when no borrow
+/// occurs. Updates C, Z and N.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this AXS instruction
+pub(crate) fn execute_axs<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    immediate(cpu, opcode, |cpu, value| {
+        let anded = cpu.a & cpu.x;
+        cpu.flag_c = anded >= value;
+        cpu.x = anded.wrapping_sub(value);
+        set_zn(cpu, cpu.x);
+    })
+}
+
+/// Executes the SHA (AHX) instruction.
+///
+/// Stores `A & X & (H + 1)`, where H is the high byte of the base address.
+/// No flags are affected.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this SHA instruction
+pub(crate) fn execute_sha<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    let value = cpu.a & cpu.x;
+    let mode = match opcode {
+        0x93 => UnstableStoreMode::IndirectY,
+        _ => UnstableStoreMode::AbsoluteY,
+    };
+    unstable_store(cpu, opcode, mode, value)
+}
+
+/// Executes the SHX instruction.
+///
+/// Stores `X & (H + 1)`, where H is the high byte of the base address.
+/// No flags are affected.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this SHX instruction
+pub(crate) fn execute_shx<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    let value = cpu.x;
+    unstable_store(cpu, opcode, UnstableStoreMode::AbsoluteY, value)
+}
+
+/// Executes the SHY instruction.
+///
+/// Stores `Y & (H + 1)`, where H is the high byte of the base address.
+/// No flags are affected.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this SHY instruction
+pub(crate) fn execute_shy<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    let value = cpu.y;
+    unstable_store(cpu, opcode, UnstableStoreMode::AbsoluteX, value)
+}
+
+/// Executes the TAS (SHS) instruction.
+///
+/// Sets the stack pointer to `A & X`, then stores `SP & (H + 1)`, where H
+/// is the high byte of the base address. No flags are affected.
+///
+/// # Arguments
+///
+/// * `cpu` - Mutable reference to the CPU
+/// * `opcode` - The opcode byte for this TAS instruction
+pub(crate) fn execute_tas<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+) -> Result<(), ExecutionError> {
+    cpu.sp = cpu.a & cpu.x;
+    let value = cpu.sp;
+    unstable_store(cpu, opcode, UnstableStoreMode::AbsoluteY, value)
+}
+
+// ========== Shared Helpers ==========
+
+/// Sets the Z and N flags from `value`.
+fn set_zn<M: MemoryBus>(cpu: &mut CPU<M>, value: u8) {
+    cpu.flag_z = value == 0;
+    cpu.flag_n = (value & 0x80) != 0;
+}
+
+/// Runs a read-modify-write combination opcode.
+///
+/// `operation` receives the original memory value, updates registers and
+/// flags, and returns the value to write back. Like the documented RMW
+/// instructions these opcodes have no page crossing penalty.
+fn read_modify_write<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+    operation: impl FnOnce(&mut CPU<M>, u8) -> u8,
+) -> Result<(), ExecutionError> {
+    let metadata = &OPCODE_TABLE[opcode as usize];
+
+    let addr = cpu.get_effective_address(metadata.addressing_mode)?;
+    let value = cpu.memory.read(addr);
+
+    let result = operation(cpu, value);
+    cpu.memory.write(addr, result);
+
+    // Update cycle count (no page crossing penalties for RMW opcodes)
+    cpu.cycles += metadata.base_cycles as u64;
+
+    // Advance PC
+    cpu.pc = cpu.pc.wrapping_add(metadata.size_bytes as u16);
+
+    Ok(())
+}
+
+/// Runs an immediate-mode opcode with the operand byte.
+fn immediate<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+    operation: impl FnOnce(&mut CPU<M>, u8),
+) -> Result<(), ExecutionError> {
+    let metadata = &OPCODE_TABLE[opcode as usize];
+
+    let (value, _page_crossed) = cpu.get_operand_value(metadata.addressing_mode)?;
+    operation(cpu, value);
+
+    cpu.cycles += metadata.base_cycles as u64;
+
+    // Advance PC
+    cpu.pc = cpu.pc.wrapping_add(metadata.size_bytes as u16);
+
+    Ok(())
+}
+
+/// Addressing modes used by the SHA, SHX, SHY and TAS stores.
+#[derive(Debug, Clone, Copy)]
+enum UnstableStoreMode {
+    AbsoluteX,
+    AbsoluteY,
+    IndirectY,
+}
+
+/// Stores `value & (H + 1)` for SHA, SHX, SHY and TAS.
+///
+/// H is the high byte of the base address before indexing. When indexing
+/// crosses a page the high byte of the target address is replaced by the
+/// stored value, as observed on NMOS hardware.
+fn unstable_store<M: MemoryBus>(
+    cpu: &mut CPU<M>,
+    opcode: u8,
+    mode: UnstableStoreMode,
+    value: u8,
+) -> Result<(), ExecutionError> {
+    let metadata = &OPCODE_TABLE[opcode as usize];
+
+    let (base_addr, index) = match mode {
+        UnstableStoreMode::AbsoluteX => (read_word(cpu, cpu.pc.wrapping_add(1)), cpu.x),
+        UnstableStoreMode::AbsoluteY => (read_word(cpu, cpu.pc.wrapping_add(1)), cpu.y),
+        UnstableStoreMode::IndirectY => {
+            // Pointer is read from zero page with wraparound
+            let zp_addr = cpu.memory.read(cpu.pc.wrapping_add(1));
+            let addr_lo = cpu.memory.read(zp_addr as u16) as u16;
+            let addr_hi = cpu.memory.read(zp_addr.wrapping_add(1) as u16) as u16;
+            ((addr_hi << 8) | addr_lo, cpu.y)
+        }
+    };
+
+    let effective_addr = base_addr.wrapping_add(index as u16);
+    let stored = value & ((base_addr >> 8) as u8).wrapping_add(1);
+
+    let addr = if (base_addr & 0xFF00) != (effective_addr & 0xFF00) {
+        ((stored as u16) << 8) | (effective_addr & 0x00FF)
+    } else {
+        effective_addr
+    };
+    cpu.memory.write(addr, stored);
+
+    // Update cycle count (stores have no page crossing penalty)
+    cpu.cycles += metadata.base_cycles as u64;
+
+    // Advance PC
+    cpu.pc = cpu.pc.wrapping_add(metadata.size_bytes as u16);
+
+    Ok(())
+}
+
+/// Reads a little-endian 16-bit word.
+fn read_word<M: MemoryBus>(cpu: &CPU<M>, addr: u16) -> u16 {
+    let lo = cpu.memory.read(addr) as u16;
+    let hi = cpu.memory.read(addr.wrapping_add(1)) as u16;
+    (hi << 8) | lo
+}
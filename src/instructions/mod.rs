@@ -15,11 +15,14 @@
 //! - **stack**: Stack operations (PHA, PHP, PLA, PLP)
 //! - **flags**: Status flag manipulation (CLC, SEC, CLI, SEI, CLD, SED, CLV)
 //! - **transfer**: Register transfer operations (TAX, TAY, TXA, TYA, TSX, TXS)
+//! - **illegal**: Undocumented NMOS opcodes (LAX, SAX, DCP, ISC, SLO, RLA, SRE, RRA, ANC,
+//!   ALR, ARR, XAA, AXS, SHA, SHX, SHY, TAS, LAS)
 
 pub mod alu;
 pub mod branches;
 pub mod control;
 pub mod flags;
+pub mod illegal;
 pub mod inc_dec;
 pub mod load_store;
 pub mod shifts;
@@ -253,6 +253,9 @@ pub(crate) fn execute_pla<M: MemoryBus>(
 ///
 /// (Bit 5 is always ignored)
 ///
+/// Like CLI and SEI, a change to I takes effect after the IRQ poll, so it
+/// delays interrupt servicing by one instruction.
+///
 /// # Arguments
 ///
 /// * `cpu` - Mutable reference to the CPU
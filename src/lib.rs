@@ -41,6 +41,7 @@
 //! ## Modules
 //!
 //! - `cpu` - CPU state and execution logic
+//! - `debugger` - Stack inspection and call tracing helpers
 //! - `memory` - MemoryBus trait and implementations
 //! - `opcodes` - Opcode metadata table
 //! - `addressing` - Addressing mode enumerations
@@ -51,10 +52,12 @@
 pub mod addressing;
 pub mod assembler;
 pub mod cpu;
+pub mod debugger;
 pub mod devices;
 pub mod disassembler;
 pub mod memory;
 pub mod opcodes;
+pub mod petscii;
 
 // Internal instruction implementations (not part of public API)
 mod instructions;
@@ -67,7 +70,9 @@ pub mod wasm;
 pub use addressing::AddressingMode;
 pub use assembler::{assemble, AssemblerError, AssemblerOutput, ErrorType, Symbol};
 pub use cpu::CPU;
-pub use devices::{Device, DeviceError, MappedMemory, RamDevice, RomDevice, Uart6551};
+pub use devices::{
+    Device, DeviceError, MappedMemory, MirroredDevice, RamDevice, RomDevice, RtcDevice, Uart6551,
+};
 pub use disassembler::{disassemble, DisassemblyOptions, Instruction};
 pub use memory::{FlatMemory, MemoryBus};
 pub use opcodes::{OpcodeMetadata, OPCODE_TABLE};
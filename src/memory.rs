@@ -103,6 +103,31 @@ pub trait MemoryBus {
     /// ```
     fn read(&self, addr: u16) -> u8;
 
+    /// Reads a byte without side effects, for debuggers and memory viewers.
+    ///
+    /// `read` is what the CPU sees, and on some devices it changes state
+    /// (reading a UART data register consumes a received byte). `peek`
+    /// returns the same value as `read` would, but leaves every device
+    /// untouched, so inspecting memory cannot change what the program sees.
+    ///
+    /// # Default Implementation
+    ///
+    /// Calls `read`. Implementations whose reads have side effects must
+    /// override it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib6502::{MemoryBus, FlatMemory};
+    ///
+    /// let mut mem = FlatMemory::new();
+    /// mem.write(0x1234, 0xAB);
+    /// assert_eq!(mem.peek(0x1234), 0xAB);
+    /// ```
+    fn peek(&self, addr: u16) -> u8 {
+        self.read(addr)
+    }
+
     /// Writes a byte to the specified 16-bit address.
     ///
     /// This method must never panic. If the address is read-only or unmapped,
@@ -169,6 +194,30 @@ pub trait MemoryBus {
     fn irq_active(&self) -> bool {
         false // Default: no interrupts
     }
+
+    /// Checks if the NMI (Non-Maskable Interrupt) line is active.
+    ///
+    /// Unlike IRQ, NMI is **edge-triggered**: the CPU samples this line after
+    /// each instruction and services an NMI only when it changes from
+    /// inactive to active. A device that keeps the line active causes a
+    /// single NMI; it must release and re-assert the line to cause another.
+    /// The I flag does not mask NMI.
+    ///
+    /// # Default Implementation
+    ///
+    /// Returns `false` (no NMI sources).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib6502::{MemoryBus, FlatMemory};
+    ///
+    /// let mem = FlatMemory::new();
+    /// assert_eq!(mem.nmi_active(), false);
+    /// ```
+    fn nmi_active(&self) -> bool {
+        false // Default: no NMI sources
+    }
 }
 
 /// Simple 64KB flat memory implementation.
@@ -225,6 +274,85 @@ impl FlatMemory {
             data: Box::new([0; 65536]),
         }
     }
+
+    /// Size in bytes of a raw memory image (the full 64KB address space).
+    pub const IMAGE_SIZE: usize = 65536;
+
+    /// Creates a FlatMemory instance from a raw 64KB memory image.
+    ///
+    /// Byte `n` of the image becomes the contents of address `n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Exactly 65536 bytes of memory contents
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(FlatMemory)` if the image has the correct size
+    /// * `Err(&str)` if the image is not exactly 64KB
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib6502::{FlatMemory, MemoryBus};
+    ///
+    /// let mut image = vec![0u8; FlatMemory::IMAGE_SIZE];
+    /// image[0xFFFC] = 0x00;
+    /// image[0xFFFD] = 0x80;
+    ///
+    /// let mem = FlatMemory::from_image(&image).unwrap();
+    /// assert_eq!(mem.read(0xFFFD), 0x80);
+    ///
+    /// // Truncated images are rejected
+    /// assert!(FlatMemory::from_image(&image[..1024]).is_err());
+    /// ```
+    pub fn from_image(image: &[u8]) -> Result<Self, &'static str> {
+        let mut memory = Self::new();
+        memory.load_image(image)?;
+        Ok(memory)
+    }
+
+    /// Replaces the entire memory contents with a raw 64KB image.
+    ///
+    /// On error the memory contents are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Exactly 65536 bytes of memory contents
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the image was loaded
+    /// * `Err(&str)` if the image is not exactly 64KB
+    pub fn load_image(&mut self, image: &[u8]) -> Result<(), &'static str> {
+        if image.len() != Self::IMAGE_SIZE {
+            return Err("Memory image must be exactly 64KB");
+        }
+
+        self.data.copy_from_slice(image);
+        Ok(())
+    }
+
+    /// Returns the entire memory contents as a raw 64KB image.
+    ///
+    /// The returned slice can be written to a file and later restored with
+    /// [`FlatMemory::from_image`] or [`FlatMemory::load_image`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lib6502::{FlatMemory, MemoryBus};
+    ///
+    /// let mut mem = FlatMemory::new();
+    /// mem.write(0x1234, 0x42);
+    ///
+    /// let image = mem.image().to_vec();
+    /// let restored = FlatMemory::from_image(&image).unwrap();
+    /// assert_eq!(restored.read(0x1234), 0x42);
+    /// ```
+    pub fn image(&self) -> &[u8] {
+        &self.data[..]
+    }
 }
 
 impl Default for FlatMemory {
@@ -264,6 +392,35 @@ mod tests {
         assert_eq!(mem.read(0x1235), 0x00);
     }
 
+    #[test]
+    fn test_flat_memory_image_round_trip() {
+        let mut mem = FlatMemory::new();
+        mem.write(0x0000, 0x01);
+        mem.write(0xFFFF, 0xFF);
+
+        let image = mem.image().to_vec();
+        assert_eq!(image.len(), FlatMemory::IMAGE_SIZE);
+
+        let mut restored = FlatMemory::new();
+        restored.load_image(&image).unwrap();
+        assert_eq!(restored.read(0x0000), 0x01);
+        assert_eq!(restored.read(0xFFFF), 0xFF);
+    }
+
+    #[test]
+    fn test_flat_memory_load_image_wrong_size() {
+        let mut mem = FlatMemory::new();
+        mem.write(0x1000, 0x42);
+
+        assert!(mem.load_image(&[0xAA; 100]).is_err());
+        assert!(mem
+            .load_image(&vec![0xAA; FlatMemory::IMAGE_SIZE + 1])
+            .is_err());
+
+        // Failed loads leave memory untouched
+        assert_eq!(mem.read(0x1000), 0x42);
+    }
+
     #[test]
     fn test_flat_memory_full_range() {
         let mut mem = FlatMemory::new();
@@ -5,7 +5,9 @@
 //!
 //! The table covers:
 //! - **151 documented instructions** - Official NMOS 6502 opcodes
-//! - **105 illegal/undocumented opcodes** - Marked with "???" mnemonic
+//! - **93 illegal/undocumented opcodes** - Stable and unstable NMOS opcodes such
+//!   as LAX, SAX, DCP and the NOP variants, flagged with `illegal: true`
+//! - **12 JAM opcodes** - Halt the processor; marked with "???" mnemonic
 //!
 //! Each opcode entry includes:
 //! - Mnemonic (instruction name)
@@ -13,6 +15,7 @@
 //! - Base cycle cost (excluding page-crossing penalties)
 //! - Instruction size in bytes
 //! - Implementation status flag
+//! - Illegal (undocumented) opcode flag
 
 use crate::addressing::AddressingMode;
 
@@ -28,6 +31,7 @@ use crate::addressing::AddressingMode;
 /// - `base_cycles`: Minimum cycle cost (page-crossing penalties added dynamically)
 /// - `size_bytes`: Total instruction size including opcode and operands (1-3 bytes)
 /// - `implemented`: Whether this instruction is currently implemented
+/// - `illegal`: Whether this is an undocumented NMOS opcode
 ///
 /// # Examples
 ///
@@ -44,7 +48,7 @@ use crate::addressing::AddressingMode;
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct OpcodeMetadata {
-    /// Instruction mnemonic (e.g., "LDA", "STA", "???" for illegal opcodes).
+    /// Instruction mnemonic (e.g., "LDA", "STA", "???" for JAM opcodes).
     pub mnemonic: &'static str,
 
     /// Addressing mode for this instruction.
@@ -52,8 +56,9 @@ pub struct OpcodeMetadata {
 
     /// Base cycle cost (before page crossing penalties).
     ///
-    /// Documented instructions have cycles in the range 1-7.
-    /// Illegal opcodes are marked with 0 cycles.
+    /// Documented instructions have cycles in the range 1-7. Illegal
+    /// read-modify-write opcodes take up to 8 cycles. JAM opcodes are marked
+    /// with 0 cycles.
     pub base_cycles: u8,
 
     /// Total instruction size in bytes (opcode + operands).
@@ -68,6 +73,13 @@ pub struct OpcodeMetadata {
     /// All entries are `false` in this foundational feature. Future instruction
     /// implementation features will set this to `true` for implemented opcodes.
     pub implemented: bool,
+
+    /// Whether this is an undocumented (illegal) NMOS 6502 opcode.
+    ///
+    /// Some illegal opcodes share a mnemonic and addressing mode with a
+    /// documented one (e.g. `$EB` is `SBC #imm`, `$1A` is `NOP`). The
+    /// assembler always prefers the documented encoding.
+    pub illegal: bool,
 }
 
 /// Complete 256-entry opcode metadata table indexed by opcode byte value.
@@ -79,8 +91,12 @@ pub struct OpcodeMetadata {
 ///
 /// - **Documented opcodes** (151 entries): Official NMOS 6502 instructions with accurate
 ///   mnemonic, addressing mode, cycle cost, and size information.
-/// - **Illegal opcodes** (105 entries): Undocumented opcodes marked with "???" mnemonic,
-///   0 cycles, size 1, and `implemented: false`.
+/// - **Illegal opcodes** (93 entries): Undocumented NMOS opcodes with their
+///   conventional mnemonics (LAX, SAX, DCP, ISC, SLO, RLA, SRE, RRA, ANC, ALR,
+///   ARR, XAA, AXS, SHA, SHX, SHY, TAS, LAS, and NOP/SBC duplicates), marked
+///   `illegal: true`.
+/// - **JAM opcodes** (12 entries): Opcodes that lock up the processor, marked
+///   with "???" mnemonic, 0 cycles, size 1, and `implemented: false`.
 ///
 /// # Examples
 ///
@@ -92,10 +108,15 @@ pub struct OpcodeMetadata {
 /// println!("{} - {} cycles, {} bytes", brk.mnemonic, brk.base_cycles, brk.size_bytes);
 /// // Output: BRK - 7 cycles, 1 bytes
 ///
-/// // Check if opcode is illegal
-/// let illegal = &OPCODE_TABLE[0x02];
-/// assert_eq!(illegal.mnemonic, "???");
-/// assert_eq!(illegal.base_cycles, 0);
+/// // Illegal opcodes carry their conventional mnemonic
+/// let lax = &OPCODE_TABLE[0xA7];
+/// assert_eq!(lax.mnemonic, "LAX");
+/// assert!(lax.illegal);
+///
+/// // JAM opcodes are not executable
+/// let jam = &OPCODE_TABLE[0x02];
+/// assert_eq!(jam.mnemonic, "???");
+/// assert_eq!(jam.base_cycles, 0);
 /// ```
 ///
 /// # Data Source
@@ -111,6 +132,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 7,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x01
     OpcodeMetadata {
@@ -119,30 +141,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0x02 - Illegal/Undocumented opcode
+    // 0x02 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0x03 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SLO",
+        addressing_mode: AddressingMode::IndirectX,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x04 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::ZeroPage,
+        base_cycles: 3,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x05
     OpcodeMetadata {
@@ -151,6 +177,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x06
     OpcodeMetadata {
@@ -159,14 +186,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x07 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SLO",
+        addressing_mode: AddressingMode::ZeroPage,
+        base_cycles: 5,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x08
     OpcodeMetadata {
@@ -175,6 +204,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x09
     OpcodeMetadata {
@@ -183,6 +213,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x0A
     OpcodeMetadata {
@@ -191,22 +222,25 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x0B - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "ANC",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x0C - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::Absolute,
+        base_cycles: 4,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x0D
     OpcodeMetadata {
@@ -215,6 +249,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x0E
     OpcodeMetadata {
@@ -223,14 +258,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x0F - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SLO",
+        addressing_mode: AddressingMode::Absolute,
+        base_cycles: 6,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x10
     OpcodeMetadata {
@@ -239,6 +276,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x11
     OpcodeMetadata {
@@ -247,30 +285,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0x12 - Illegal/Undocumented opcode
+    // 0x12 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0x13 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SLO",
+        addressing_mode: AddressingMode::IndirectY,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x14 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 4,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x15
     OpcodeMetadata {
@@ -279,6 +321,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x16
     OpcodeMetadata {
@@ -287,14 +330,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x17 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SLO",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 6,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x18
     OpcodeMetadata {
@@ -303,6 +348,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x19
     OpcodeMetadata {
@@ -311,30 +357,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x1A - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
+        mnemonic: "NOP",
         addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
+        base_cycles: 2,
         size_bytes: 1,
-        implemented: false,
+        implemented: true,
+        illegal: true,
     },
     // 0x1B - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SLO",
+        addressing_mode: AddressingMode::AbsoluteY,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x1C - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 4,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x1D
     OpcodeMetadata {
@@ -343,6 +393,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x1E
     OpcodeMetadata {
@@ -351,14 +402,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 7,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x1F - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SLO",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x20
     OpcodeMetadata {
@@ -367,6 +420,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x21
     OpcodeMetadata {
@@ -375,22 +429,25 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0x22 - Illegal/Undocumented opcode
+    // 0x22 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0x23 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RLA",
+        addressing_mode: AddressingMode::IndirectX,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x24
     OpcodeMetadata {
@@ -399,6 +456,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x25
     OpcodeMetadata {
@@ -407,6 +465,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x26
     OpcodeMetadata {
@@ -415,14 +474,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x27 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RLA",
+        addressing_mode: AddressingMode::ZeroPage,
+        base_cycles: 5,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x28
     OpcodeMetadata {
@@ -431,6 +492,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x29
     OpcodeMetadata {
@@ -439,6 +501,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x2A
     OpcodeMetadata {
@@ -447,14 +510,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x2B - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "ANC",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x2C
     OpcodeMetadata {
@@ -463,6 +528,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x2D
     OpcodeMetadata {
@@ -471,6 +537,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x2E
     OpcodeMetadata {
@@ -479,14 +546,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x2F - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RLA",
+        addressing_mode: AddressingMode::Absolute,
+        base_cycles: 6,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x30
     OpcodeMetadata {
@@ -495,6 +564,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x31
     OpcodeMetadata {
@@ -503,30 +573,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0x32 - Illegal/Undocumented opcode
+    // 0x32 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0x33 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RLA",
+        addressing_mode: AddressingMode::IndirectY,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x34 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 4,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x35
     OpcodeMetadata {
@@ -535,6 +609,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x36
     OpcodeMetadata {
@@ -543,14 +618,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x37 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RLA",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 6,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x38
     OpcodeMetadata {
@@ -559,6 +636,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x39
     OpcodeMetadata {
@@ -567,30 +645,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x3A - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
+        mnemonic: "NOP",
         addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
+        base_cycles: 2,
         size_bytes: 1,
-        implemented: false,
+        implemented: true,
+        illegal: true,
     },
     // 0x3B - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RLA",
+        addressing_mode: AddressingMode::AbsoluteY,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x3C - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 4,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x3D
     OpcodeMetadata {
@@ -599,6 +681,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x3E
     OpcodeMetadata {
@@ -607,14 +690,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 7,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x3F - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RLA",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x40
     OpcodeMetadata {
@@ -623,6 +708,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x41
     OpcodeMetadata {
@@ -631,30 +717,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0x42 - Illegal/Undocumented opcode
+    // 0x42 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0x43 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SRE",
+        addressing_mode: AddressingMode::IndirectX,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x44 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::ZeroPage,
+        base_cycles: 3,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x45
     OpcodeMetadata {
@@ -663,6 +753,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x46
     OpcodeMetadata {
@@ -671,14 +762,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x47 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SRE",
+        addressing_mode: AddressingMode::ZeroPage,
+        base_cycles: 5,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x48
     OpcodeMetadata {
@@ -687,6 +780,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x49
     OpcodeMetadata {
@@ -695,6 +789,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x4A
     OpcodeMetadata {
@@ -703,14 +798,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x4B - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "ALR",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x4C
     OpcodeMetadata {
@@ -719,6 +816,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x4D
     OpcodeMetadata {
@@ -727,6 +825,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x4E
     OpcodeMetadata {
@@ -735,14 +834,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x4F - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SRE",
+        addressing_mode: AddressingMode::Absolute,
+        base_cycles: 6,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x50
     OpcodeMetadata {
@@ -751,6 +852,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x51
     OpcodeMetadata {
@@ -759,30 +861,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0x52 - Illegal/Undocumented opcode
+    // 0x52 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0x53 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SRE",
+        addressing_mode: AddressingMode::IndirectY,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x54 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 4,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x55
     OpcodeMetadata {
@@ -791,6 +897,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x56
     OpcodeMetadata {
@@ -799,14 +906,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x57 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SRE",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 6,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x58
     OpcodeMetadata {
@@ -815,6 +924,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x59
     OpcodeMetadata {
@@ -823,30 +933,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x5A - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
+        mnemonic: "NOP",
         addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
+        base_cycles: 2,
         size_bytes: 1,
-        implemented: false,
+        implemented: true,
+        illegal: true,
     },
     // 0x5B - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SRE",
+        addressing_mode: AddressingMode::AbsoluteY,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x5C - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 4,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x5D
     OpcodeMetadata {
@@ -855,6 +969,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x5E
     OpcodeMetadata {
@@ -863,14 +978,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 7,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x5F - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SRE",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x60
     OpcodeMetadata {
@@ -879,6 +996,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x61
     OpcodeMetadata {
@@ -887,30 +1005,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0x62 - Illegal/Undocumented opcode
+    // 0x62 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0x63 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RRA",
+        addressing_mode: AddressingMode::IndirectX,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x64 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::ZeroPage,
+        base_cycles: 3,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x65
     OpcodeMetadata {
@@ -919,6 +1041,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x66
     OpcodeMetadata {
@@ -927,14 +1050,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x67 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RRA",
+        addressing_mode: AddressingMode::ZeroPage,
+        base_cycles: 5,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x68
     OpcodeMetadata {
@@ -943,6 +1068,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x69
     OpcodeMetadata {
@@ -951,6 +1077,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x6A
     OpcodeMetadata {
@@ -959,14 +1086,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x6B - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "ARR",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x6C
     OpcodeMetadata {
@@ -975,6 +1104,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x6D
     OpcodeMetadata {
@@ -983,6 +1113,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x6E
     OpcodeMetadata {
@@ -991,14 +1122,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x6F - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RRA",
+        addressing_mode: AddressingMode::Absolute,
+        base_cycles: 6,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x70
     OpcodeMetadata {
@@ -1007,6 +1140,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x71
     OpcodeMetadata {
@@ -1015,30 +1149,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0x72 - Illegal/Undocumented opcode
+    // 0x72 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0x73 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RRA",
+        addressing_mode: AddressingMode::IndirectY,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x74 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 4,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x75
     OpcodeMetadata {
@@ -1047,6 +1185,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x76
     OpcodeMetadata {
@@ -1055,14 +1194,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x77 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RRA",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 6,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x78
     OpcodeMetadata {
@@ -1071,6 +1212,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x79
     OpcodeMetadata {
@@ -1079,30 +1221,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x7A - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
+        mnemonic: "NOP",
         addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
+        base_cycles: 2,
         size_bytes: 1,
-        implemented: false,
+        implemented: true,
+        illegal: true,
     },
     // 0x7B - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RRA",
+        addressing_mode: AddressingMode::AbsoluteY,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x7C - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 4,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x7D
     OpcodeMetadata {
@@ -1111,6 +1257,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x7E
     OpcodeMetadata {
@@ -1119,22 +1266,25 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 7,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x7F - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "RRA",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x80 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x81
     OpcodeMetadata {
@@ -1143,22 +1293,25 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x82 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x83 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SAX",
+        addressing_mode: AddressingMode::IndirectX,
+        base_cycles: 6,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x84
     OpcodeMetadata {
@@ -1167,6 +1320,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x85
     OpcodeMetadata {
@@ -1175,6 +1329,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x86
     OpcodeMetadata {
@@ -1183,14 +1338,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x87 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SAX",
+        addressing_mode: AddressingMode::ZeroPage,
+        base_cycles: 3,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x88
     OpcodeMetadata {
@@ -1199,14 +1356,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x89 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x8A
     OpcodeMetadata {
@@ -1215,14 +1374,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x8B - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "XAA",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x8C
     OpcodeMetadata {
@@ -1231,6 +1392,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x8D
     OpcodeMetadata {
@@ -1239,6 +1401,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x8E
     OpcodeMetadata {
@@ -1247,14 +1410,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x8F - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SAX",
+        addressing_mode: AddressingMode::Absolute,
+        base_cycles: 4,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x90
     OpcodeMetadata {
@@ -1263,6 +1428,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x91
     OpcodeMetadata {
@@ -1271,22 +1437,25 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0x92 - Illegal/Undocumented opcode
+    // 0x92 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0x93 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SHA",
+        addressing_mode: AddressingMode::IndirectY,
+        base_cycles: 6,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x94
     OpcodeMetadata {
@@ -1295,6 +1464,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x95
     OpcodeMetadata {
@@ -1303,6 +1473,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x96
     OpcodeMetadata {
@@ -1311,14 +1482,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0x97 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SAX",
+        addressing_mode: AddressingMode::ZeroPageY,
+        base_cycles: 4,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0x98
     OpcodeMetadata {
@@ -1327,6 +1500,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x99
     OpcodeMetadata {
@@ -1335,6 +1509,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x9A
     OpcodeMetadata {
@@ -1343,22 +1518,25 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0x9B - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "TAS",
+        addressing_mode: AddressingMode::AbsoluteY,
+        base_cycles: 5,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x9C - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SHY",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 5,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x9D
     OpcodeMetadata {
@@ -1367,22 +1545,25 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0x9E - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SHX",
+        addressing_mode: AddressingMode::AbsoluteY,
+        base_cycles: 5,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0x9F - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SHA",
+        addressing_mode: AddressingMode::AbsoluteY,
+        base_cycles: 5,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0xA0
     OpcodeMetadata {
@@ -1391,6 +1572,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xA1
     OpcodeMetadata {
@@ -1399,6 +1581,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xA2
     OpcodeMetadata {
@@ -1407,14 +1590,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xA3 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "LAX",
+        addressing_mode: AddressingMode::IndirectX,
+        base_cycles: 6,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xA4
     OpcodeMetadata {
@@ -1423,6 +1608,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xA5
     OpcodeMetadata {
@@ -1431,6 +1617,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xA6
     OpcodeMetadata {
@@ -1439,14 +1626,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xA7 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "LAX",
+        addressing_mode: AddressingMode::ZeroPage,
+        base_cycles: 3,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xA8
     OpcodeMetadata {
@@ -1455,6 +1644,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0xA9
     OpcodeMetadata {
@@ -1463,6 +1653,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xAA
     OpcodeMetadata {
@@ -1471,14 +1662,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0xAB - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "LAX",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xAC
     OpcodeMetadata {
@@ -1487,6 +1680,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xAD
     OpcodeMetadata {
@@ -1495,6 +1689,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xAE
     OpcodeMetadata {
@@ -1503,14 +1698,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xAF - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "LAX",
+        addressing_mode: AddressingMode::Absolute,
+        base_cycles: 4,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0xB0
     OpcodeMetadata {
@@ -1519,6 +1716,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xB1
     OpcodeMetadata {
@@ -1527,22 +1725,25 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0xB2 - Illegal/Undocumented opcode
+    // 0xB2 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0xB3 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "LAX",
+        addressing_mode: AddressingMode::IndirectY,
+        base_cycles: 5,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xB4
     OpcodeMetadata {
@@ -1551,6 +1752,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xB5
     OpcodeMetadata {
@@ -1559,6 +1761,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xB6
     OpcodeMetadata {
@@ -1567,14 +1770,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xB7 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "LAX",
+        addressing_mode: AddressingMode::ZeroPageY,
+        base_cycles: 4,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xB8
     OpcodeMetadata {
@@ -1583,6 +1788,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0xB9
     OpcodeMetadata {
@@ -1591,6 +1797,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xBA
     OpcodeMetadata {
@@ -1599,14 +1806,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0xBB - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "LAS",
+        addressing_mode: AddressingMode::AbsoluteY,
+        base_cycles: 4,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0xBC
     OpcodeMetadata {
@@ -1615,6 +1824,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xBD
     OpcodeMetadata {
@@ -1623,6 +1833,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xBE
     OpcodeMetadata {
@@ -1631,14 +1842,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xBF - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "LAX",
+        addressing_mode: AddressingMode::AbsoluteY,
+        base_cycles: 4,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0xC0
     OpcodeMetadata {
@@ -1647,6 +1860,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xC1
     OpcodeMetadata {
@@ -1655,22 +1869,25 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xC2 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xC3 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "DCP",
+        addressing_mode: AddressingMode::IndirectX,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xC4
     OpcodeMetadata {
@@ -1679,6 +1896,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xC5
     OpcodeMetadata {
@@ -1687,6 +1905,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xC6
     OpcodeMetadata {
@@ -1695,14 +1914,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xC7 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "DCP",
+        addressing_mode: AddressingMode::ZeroPage,
+        base_cycles: 5,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xC8
     OpcodeMetadata {
@@ -1711,6 +1932,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0xC9
     OpcodeMetadata {
@@ -1719,6 +1941,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xCA
     OpcodeMetadata {
@@ -1727,14 +1950,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0xCB - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "AXS",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xCC
     OpcodeMetadata {
@@ -1743,6 +1968,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xCD
     OpcodeMetadata {
@@ -1751,6 +1977,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xCE
     OpcodeMetadata {
@@ -1759,14 +1986,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xCF - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "DCP",
+        addressing_mode: AddressingMode::Absolute,
+        base_cycles: 6,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0xD0
     OpcodeMetadata {
@@ -1775,6 +2004,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xD1
     OpcodeMetadata {
@@ -1783,30 +2013,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0xD2 - Illegal/Undocumented opcode
+    // 0xD2 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0xD3 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "DCP",
+        addressing_mode: AddressingMode::IndirectY,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xD4 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 4,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xD5
     OpcodeMetadata {
@@ -1815,6 +2049,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xD6
     OpcodeMetadata {
@@ -1823,14 +2058,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xD7 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "DCP",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 6,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xD8
     OpcodeMetadata {
@@ -1839,6 +2076,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0xD9
     OpcodeMetadata {
@@ -1847,30 +2085,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xDA - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
+        mnemonic: "NOP",
         addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
+        base_cycles: 2,
         size_bytes: 1,
-        implemented: false,
+        implemented: true,
+        illegal: true,
     },
     // 0xDB - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "DCP",
+        addressing_mode: AddressingMode::AbsoluteY,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0xDC - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 4,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0xDD
     OpcodeMetadata {
@@ -1879,6 +2121,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xDE
     OpcodeMetadata {
@@ -1887,14 +2130,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 7,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xDF - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "DCP",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0xE0
     OpcodeMetadata {
@@ -1903,6 +2148,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xE1
     OpcodeMetadata {
@@ -1911,22 +2157,25 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xE2 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xE3 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "ISC",
+        addressing_mode: AddressingMode::IndirectX,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xE4
     OpcodeMetadata {
@@ -1935,6 +2184,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xE5
     OpcodeMetadata {
@@ -1943,6 +2193,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 3,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xE6
     OpcodeMetadata {
@@ -1951,14 +2202,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xE7 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "ISC",
+        addressing_mode: AddressingMode::ZeroPage,
+        base_cycles: 5,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xE8
     OpcodeMetadata {
@@ -1967,6 +2220,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0xE9
     OpcodeMetadata {
@@ -1975,6 +2229,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xEA
     OpcodeMetadata {
@@ -1983,14 +2238,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0xEB - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "SBC",
+        addressing_mode: AddressingMode::Immediate,
+        base_cycles: 2,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xEC
     OpcodeMetadata {
@@ -1999,6 +2256,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xED
     OpcodeMetadata {
@@ -2007,6 +2265,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xEE
     OpcodeMetadata {
@@ -2015,14 +2274,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xEF - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "ISC",
+        addressing_mode: AddressingMode::Absolute,
+        base_cycles: 6,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0xF0
     OpcodeMetadata {
@@ -2031,6 +2292,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xF1
     OpcodeMetadata {
@@ -2039,30 +2301,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 5,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
-    // 0xF2 - Illegal/Undocumented opcode
+    // 0xF2 - Illegal/Undocumented opcode (JAM: halts the CPU)
     OpcodeMetadata {
         mnemonic: "???",
         addressing_mode: AddressingMode::Implicit,
         base_cycles: 0,
         size_bytes: 1,
         implemented: false,
+        illegal: true,
     },
     // 0xF3 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "ISC",
+        addressing_mode: AddressingMode::IndirectY,
+        base_cycles: 8,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xF4 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 4,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xF5
     OpcodeMetadata {
@@ -2071,6 +2337,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xF6
     OpcodeMetadata {
@@ -2079,14 +2346,16 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 6,
         size_bytes: 2,
         implemented: true,
+        illegal: false,
     },
     // 0xF7 - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "ISC",
+        addressing_mode: AddressingMode::ZeroPageX,
+        base_cycles: 6,
+        size_bytes: 2,
+        implemented: true,
+        illegal: true,
     },
     // 0xF8
     OpcodeMetadata {
@@ -2095,6 +2364,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 2,
         size_bytes: 1,
         implemented: true,
+        illegal: false,
     },
     // 0xF9
     OpcodeMetadata {
@@ -2103,30 +2373,34 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xFA - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
+        mnemonic: "NOP",
         addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
+        base_cycles: 2,
         size_bytes: 1,
-        implemented: false,
+        implemented: true,
+        illegal: true,
     },
     // 0xFB - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "ISC",
+        addressing_mode: AddressingMode::AbsoluteY,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0xFC - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "NOP",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 4,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
     // 0xFD
     OpcodeMetadata {
@@ -2135,6 +2409,7 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 4,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xFE
     OpcodeMetadata {
@@ -2143,13 +2418,55 @@ pub const OPCODE_TABLE: [OpcodeMetadata; 256] = [
         base_cycles: 7,
         size_bytes: 3,
         implemented: true,
+        illegal: false,
     },
     // 0xFF - Illegal/Undocumented opcode
     OpcodeMetadata {
-        mnemonic: "???",
-        addressing_mode: AddressingMode::Implicit,
-        base_cycles: 0,
-        size_bytes: 1,
-        implemented: false,
+        mnemonic: "ISC",
+        addressing_mode: AddressingMode::AbsoluteX,
+        base_cycles: 7,
+        size_bytes: 3,
+        implemented: true,
+        illegal: true,
     },
 ];
+
+/// Returns the opcode the assembler emits for a mnemonic and addressing mode.
+///
+/// Documented opcodes take precedence over illegal duplicates, so `NOP`
+/// encodes as $EA rather than $1A and `SBC #` as $E9 rather than $EB.
+/// Among illegal duplicates with no documented form (the NOP variants) the
+/// lowest opcode wins.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::opcodes::canonical_opcode;
+/// use lib6502::AddressingMode;
+///
+/// assert_eq!(canonical_opcode("SBC", AddressingMode::Immediate), Some(0xE9));
+/// assert_eq!(canonical_opcode("NOP", AddressingMode::ZeroPageX), Some(0x14));
+/// assert_eq!(canonical_opcode("XYZ", AddressingMode::Implicit), None);
+/// ```
+pub fn canonical_opcode(mnemonic: &str, mode: AddressingMode) -> Option<u8> {
+    let matches = |metadata: &OpcodeMetadata| {
+        metadata.mnemonic == mnemonic && metadata.addressing_mode == mode
+    };
+
+    OPCODE_TABLE
+        .iter()
+        .position(|metadata| matches(metadata) && !metadata.illegal)
+        .or_else(|| OPCODE_TABLE.iter().position(matches))
+        .map(|opcode| opcode as u8)
+}
+
+/// Returns true if `opcode` is the encoding the assembler picks for its own
+/// mnemonic and addressing mode.
+///
+/// Only illegal duplicates such as $54 (`NOP zp,X`, which assembles to $14)
+/// or $EB (`SBC #`, which assembles to $E9) return false. The disassembler
+/// shows those as `.byte` so its output reassembles byte-for-byte.
+pub fn has_canonical_encoding(opcode: u8) -> bool {
+    let metadata = &OPCODE_TABLE[opcode as usize];
+    canonical_opcode(metadata.mnemonic, metadata.addressing_mode) == Some(opcode)
+}
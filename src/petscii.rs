@@ -0,0 +1,302 @@
+//! PETSCII and screen code conversion.
+//!
+//! Commodore 8-bit machines store text as PETSCII and draw it from screen
+//! codes, the glyph indices in character ROM. Both are interpreted through one
+//! of two character sets, selected at runtime on the real machine:
+//!
+//! - [`CharSet::Unshifted`]: uppercase letters and graphics (power-on default)
+//! - [`CharSet::Shifted`]: lowercase and uppercase letters, fewer graphics
+//!
+//! Glyphs map to Unicode following the Unicode Consortium's Commodore 64
+//! mapping tables, which use the Symbols for Legacy Computing block for the
+//! graphics that have no older equivalent. PETSCII control codes ($00-$1F and
+//! $80-$9F) map to the C0 and C1 control characters with the same value, so
+//! RETURN ($0D) decodes as `'\r'`.
+//!
+//! Nothing here is used by the CPU; the module is for hosts that exchange text
+//! with Commodore software (disk images, keyboard input, screen scraping).
+//!
+//! # Duplicate Codes
+//!
+//! PETSCII $60-$7F print the same glyphs as $C0-$DF, and $E0-$FE the same as
+//! $A0-$BE, with $FF matching $DE. Decoding accepts every byte; encoding
+//! always produces the canonical $C0-$DF and $A0-$BF codes, as the KERNAL
+//! keyboard routines do.
+
+/// Which of the two character sets text is interpreted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CharSet {
+    /// Uppercase letters and graphics (the power-on character set)
+    #[default]
+    Unshifted,
+
+    /// Lowercase and uppercase letters
+    Shifted,
+}
+
+/// Screen codes $00-$7F in the unshifted set. $80-$FF are the same glyphs in
+/// reverse video.
+#[rustfmt::skip]
+const UNSHIFTED_GLYPHS: [char; 128] = [
+    // $00-$1F: @, letters, punctuation
+    '@', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z', '[', '£', ']', '↑', '←',
+    // $20-$3F: same as ASCII
+    ' ', '!', '"', '#', '$', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/',
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?',
+    // $40-$5F: graphics
+    '\u{2500}', '\u{2660}', '\u{1FB72}', '\u{1FB78}', '\u{1FB77}', '\u{1FB76}', '\u{1FB7A}', '\u{1FB71}',
+    '\u{1FB74}', '\u{256E}', '\u{2570}', '\u{256F}', '\u{1FB7C}', '\u{2572}', '\u{2571}', '\u{1FB7D}',
+    '\u{1FB7E}', '\u{25CF}', '\u{1FB7B}', '\u{2665}', '\u{1FB70}', '\u{256D}', '\u{2573}', '\u{25CB}',
+    '\u{2663}', '\u{1FB75}', '\u{2666}', '\u{253C}', '\u{1FB8C}', '\u{2502}', '\u{03C0}', '\u{25E5}',
+    // $60-$7F: graphics
+    '\u{00A0}', '\u{258C}', '\u{2584}', '\u{2594}', '\u{2581}', '\u{258F}', '\u{2592}', '\u{2595}',
+    '\u{1FB8F}', '\u{25E4}', '\u{1FB87}', '\u{251C}', '\u{2597}', '\u{2514}', '\u{2510}', '\u{2582}',
+    '\u{250C}', '\u{2534}', '\u{252C}', '\u{2524}', '\u{258E}', '\u{258D}', '\u{1FB88}', '\u{1FB82}',
+    '\u{1FB83}', '\u{2583}', '\u{1FB7F}', '\u{2596}', '\u{259D}', '\u{2518}', '\u{2598}', '\u{259A}',
+];
+
+/// Screen codes $00-$7F in the shifted set. $80-$FF are the same glyphs in
+/// reverse video.
+#[rustfmt::skip]
+const SHIFTED_GLYPHS: [char; 128] = [
+    // $00-$1F: @, lowercase letters, punctuation
+    '@', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n', 'o',
+    'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '[', '£', ']', '↑', '←',
+    // $20-$3F: same as ASCII
+    ' ', '!', '"', '#', '$', '%', '&', '\'', '(', ')', '*', '+', ',', '-', '.', '/',
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', ':', ';', '<', '=', '>', '?',
+    // $40-$5F: uppercase letters and graphics
+    '\u{2500}', 'A', 'B', 'C', 'D', 'E', 'F', 'G',
+    'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O',
+    'P', 'Q', 'R', 'S', 'T', 'U', 'V', 'W',
+    'X', 'Y', 'Z', '\u{253C}', '\u{1FB8C}', '\u{2502}', '\u{1FB96}', '\u{1FB98}',
+    // $60-$7F: graphics
+    '\u{00A0}', '\u{258C}', '\u{2584}', '\u{2594}', '\u{2581}', '\u{258F}', '\u{2592}', '\u{2595}',
+    '\u{1FB8F}', '\u{1FB99}', '\u{1FB87}', '\u{251C}', '\u{2597}', '\u{2514}', '\u{2510}', '\u{2582}',
+    '\u{250C}', '\u{2534}', '\u{252C}', '\u{2524}', '\u{258E}', '\u{258D}', '\u{1FB88}', '\u{1FB82}',
+    '\u{1FB83}', '\u{2583}', '\u{2713}', '\u{2596}', '\u{259D}', '\u{2518}', '\u{2598}', '\u{259A}',
+];
+
+impl CharSet {
+    fn glyphs(self) -> &'static [char; 128] {
+        match self {
+            CharSet::Unshifted => &UNSHIFTED_GLYPHS,
+            CharSet::Shifted => &SHIFTED_GLYPHS,
+        }
+    }
+}
+
+/// Returns true for PETSCII control codes ($00-$1F and $80-$9F).
+pub fn is_control(byte: u8) -> bool {
+    byte & 0x7F < 0x20
+}
+
+/// Convert a PETSCII byte to the screen code that displays it.
+///
+/// Returns `None` for control codes, which have no glyph.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::petscii::petscii_to_screen_code;
+///
+/// assert_eq!(petscii_to_screen_code(0x41), Some(0x01)); // A
+/// assert_eq!(petscii_to_screen_code(0x20), Some(0x20)); // space
+/// assert_eq!(petscii_to_screen_code(0x0D), None); // RETURN
+/// ```
+pub fn petscii_to_screen_code(byte: u8) -> Option<u8> {
+    match byte {
+        _ if is_control(byte) => None,
+        0x20..=0x3F => Some(byte),
+        0x40..=0x5F => Some(byte - 0x40),
+        0x60..=0x7F => Some(byte - 0x20),
+        0xA0..=0xBF => Some(byte - 0x40),
+        0xC0..=0xDF => Some(byte - 0x80),
+        0xE0..=0xFE => Some(byte - 0x80),
+        _ => Some(0x5E), // $FF is a duplicate of $DE
+    }
+}
+
+/// Convert a screen code to its canonical PETSCII byte.
+///
+/// The reverse video bit (bit 7) is ignored; PETSCII expresses reverse video
+/// with the RVS ON/OFF control codes instead.
+pub fn screen_code_to_petscii(code: u8) -> u8 {
+    match code & 0x7F {
+        code @ 0x00..=0x1F => code + 0x40,
+        code @ 0x20..=0x3F => code,
+        code @ 0x40..=0x5F => code + 0x80,
+        code => code + 0x40,
+    }
+}
+
+/// Convert a screen code to the Unicode character it displays.
+///
+/// The reverse video bit (bit 7) is ignored.
+pub fn screen_code_to_char(code: u8, set: CharSet) -> char {
+    set.glyphs()[(code & 0x7F) as usize]
+}
+
+/// Convert a Unicode character to the screen code that displays it.
+///
+/// Returns `None` if the character set has no such glyph.
+pub fn char_to_screen_code(c: char, set: CharSet) -> Option<u8> {
+    set.glyphs()
+        .iter()
+        .position(|&glyph| glyph == c)
+        .map(|code| code as u8)
+}
+
+/// Convert a PETSCII byte to a Unicode character.
+///
+/// Every byte converts: control codes become the C0/C1 control with the same
+/// value.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::petscii::{petscii_to_char, CharSet};
+///
+/// assert_eq!(petscii_to_char(0x41, CharSet::Unshifted), 'A');
+/// assert_eq!(petscii_to_char(0x41, CharSet::Shifted), 'a');
+/// assert_eq!(petscii_to_char(0xC1, CharSet::Shifted), 'A');
+/// assert_eq!(petscii_to_char(0xC1, CharSet::Unshifted), '♠');
+/// ```
+pub fn petscii_to_char(byte: u8, set: CharSet) -> char {
+    match petscii_to_screen_code(byte) {
+        Some(code) => screen_code_to_char(code, set),
+        None => char::from(byte),
+    }
+}
+
+/// Convert a Unicode character to a canonical PETSCII byte.
+///
+/// Returns `None` if the character set has no such character. ASCII
+/// lowercase letters only exist in the shifted set.
+pub fn char_to_petscii(c: char, set: CharSet) -> Option<u8> {
+    match u8::try_from(c) {
+        Ok(byte) if is_control(byte) => Some(byte),
+        _ => char_to_screen_code(c, set).map(screen_code_to_petscii),
+    }
+}
+
+/// Decode PETSCII bytes into a string.
+pub fn decode(bytes: &[u8], set: CharSet) -> String {
+    bytes
+        .iter()
+        .map(|&byte| petscii_to_char(byte, set))
+        .collect()
+}
+
+/// Encode a string as PETSCII.
+///
+/// # Errors
+///
+/// Returns the first character that has no PETSCII code in `set`.
+///
+/// # Examples
+///
+/// ```
+/// use lib6502::petscii::{decode, encode, CharSet};
+///
+/// let bytes = encode("Hello, World!", CharSet::Shifted).unwrap();
+/// assert_eq!(bytes[..5], [0xC8, 0x45, 0x4C, 0x4C, 0x4F]);
+/// assert_eq!(decode(&bytes, CharSet::Shifted), "Hello, World!");
+///
+/// assert_eq!(encode("Hello", CharSet::Unshifted), Err('e'));
+/// ```
+pub fn encode(text: &str, set: CharSet) -> Result<Vec<u8>, char> {
+    text.chars()
+        .map(|c| char_to_petscii(c, set).ok_or(c))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glyph_tables_have_no_duplicates() {
+        for set in [CharSet::Unshifted, CharSet::Shifted] {
+            for code in 0x00..0x80u8 {
+                let c = screen_code_to_char(code, set);
+                assert_eq!(
+                    char_to_screen_code(c, set),
+                    Some(code),
+                    "{:?} {:02X}",
+                    set,
+                    code
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_every_petscii_byte_round_trips_to_canonical_code() {
+        for set in [CharSet::Unshifted, CharSet::Shifted] {
+            for byte in 0x00..=0xFFu8 {
+                let canonical = match byte {
+                    0x60..=0x7F => byte + 0x60,
+                    0xE0..=0xFE => byte - 0x40,
+                    0xFF => 0xDE,
+                    _ => byte,
+                };
+                let c = petscii_to_char(byte, set);
+                assert_eq!(
+                    char_to_petscii(c, set),
+                    Some(canonical),
+                    "{:?} {:02X}",
+                    set,
+                    byte
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_screen_code_conversion() {
+        assert_eq!(petscii_to_screen_code(0x40), Some(0x00)); // @
+        assert_eq!(petscii_to_screen_code(0x5F), Some(0x1F)); // ←
+        assert_eq!(petscii_to_screen_code(0xC1), Some(0x41));
+        assert_eq!(petscii_to_screen_code(0x61), Some(0x41));
+        assert_eq!(petscii_to_screen_code(0xA0), Some(0x60));
+        assert_eq!(petscii_to_screen_code(0xE0), Some(0x60));
+        assert_eq!(petscii_to_screen_code(0xFF), Some(0x5E));
+        assert_eq!(petscii_to_screen_code(0x93), None); // CLR
+
+        // Reverse video shares the glyph and PETSCII code
+        assert_eq!(screen_code_to_petscii(0x81), screen_code_to_petscii(0x01));
+        assert_eq!(screen_code_to_char(0x81, CharSet::Unshifted), 'A');
+
+        for code in 0x00..0x80u8 {
+            assert_eq!(
+                petscii_to_screen_code(screen_code_to_petscii(code)),
+                Some(code)
+            );
+        }
+    }
+
+    #[test]
+    fn test_character_sets_differ() {
+        assert_eq!(petscii_to_char(0x5C, CharSet::Unshifted), '£');
+        assert_eq!(petscii_to_char(0xDE, CharSet::Unshifted), 'π');
+        assert_eq!(petscii_to_char(0xDE, CharSet::Shifted), '\u{1FB96}');
+        assert_eq!(petscii_to_char(0xBA, CharSet::Shifted), '✓');
+        assert_eq!(char_to_petscii('a', CharSet::Unshifted), None);
+        assert_eq!(char_to_petscii('π', CharSet::Shifted), None);
+    }
+
+    #[test]
+    fn test_control_codes() {
+        assert!(is_control(0x0D));
+        assert!(is_control(0x93));
+        assert!(!is_control(0x20));
+        assert!(!is_control(0xA0));
+
+        assert_eq!(petscii_to_char(0x0D, CharSet::Unshifted), '\r');
+        assert_eq!(char_to_petscii('\r', CharSet::Shifted), Some(0x0D));
+        assert_eq!(char_to_petscii('\u{93}', CharSet::Shifted), Some(0x93));
+        assert_eq!(decode(&[0x48, 0x49, 0x0D], CharSet::Unshifted), "HI\r");
+    }
+}
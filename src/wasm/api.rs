@@ -3,6 +3,8 @@
 //! Provides JavaScript-callable interfaces for CPU control, state inspection,
 //! and assembly/disassembly operations.
 
+use crate::debugger::{refine, scan, BytePattern, GdbStub, Monitor};
+use crate::petscii::CharSet;
 use crate::{
     assemble, disassemble, Device, DisassemblyOptions, MappedMemory, MemoryBus, RamDevice,
     RomDevice, Uart6551, CPU,
@@ -119,6 +121,8 @@ pub struct Emulator6502 {
     on_transmit: js_sys::Function,
     program_start: u16,
     program_end: u16,
+    monitor: Monitor,
+    gdb: GdbStub,
 }
 
 #[wasm_bindgen]
@@ -171,6 +175,8 @@ impl Emulator6502 {
             on_transmit,
             program_start: 0x0600,
             program_end: 0x0600,
+            monitor: Monitor::new(),
+            gdb: GdbStub::new(),
         }
     }
 
@@ -194,11 +200,11 @@ impl Emulator6502 {
         // Save current RAM contents (including vector page)
         let mut ram_backup = Vec::with_capacity(32768);
         for addr in 0x0000..=0x7FFF {
-            ram_backup.push(self.cpu.memory.read(addr));
+            ram_backup.push(self.cpu.memory.peek(addr));
         }
         let mut vector_backup = Vec::with_capacity(256);
         for addr in 0xFF00..=0xFFFF {
-            vector_backup.push(self.cpu.memory.read(addr));
+            vector_backup.push(self.cpu.memory.peek(addr));
         }
 
         // Create new MappedMemory
@@ -326,7 +332,7 @@ impl Emulator6502 {
 
     /// Read a single byte from memory
     pub fn read_memory(&self, addr: u16) -> u8 {
-        self.cpu.memory.read(addr)
+        self.cpu.memory.peek(addr)
     }
 
     /// Write a single byte to memory
@@ -337,7 +343,60 @@ impl Emulator6502 {
     /// Read a 256-byte page from memory (for efficient display)
     pub fn get_memory_page(&self, page: u8) -> Vec<u8> {
         let start = (page as u16) << 8;
-        (0..256).map(|i| self.cpu.memory.read(start + i)).collect()
+        (0..256).map(|i| self.cpu.memory.peek(start + i)).collect()
+    }
+
+    /// Search memory for a hex byte pattern such as `"A9 ?? 8D"`
+    ///
+    /// Returns at most `max_results` match addresses within `start..=end`.
+    pub fn find_memory(
+        &self,
+        pattern: &str,
+        start: u16,
+        end: u16,
+        max_results: u32,
+    ) -> Result<Vec<u16>, JsError> {
+        let pattern = BytePattern::parse(pattern).map_err(|e| JsError::new(&e))?;
+        Ok(scan(&self.cpu.memory, &pattern, start..=end)
+            .take(max_results as usize)
+            .collect())
+    }
+
+    /// Search memory for text encoded as `"ascii"`, `"petscii"` or `"screen"`
+    /// codes, using the shifted (lowercase) character set if `shifted` is set
+    ///
+    /// Returns at most `max_results` match addresses within `start..=end`.
+    pub fn find_text(
+        &self,
+        text: &str,
+        encoding: &str,
+        shifted: bool,
+        start: u16,
+        end: u16,
+        max_results: u32,
+    ) -> Result<Vec<u16>, JsError> {
+        let set = if shifted {
+            CharSet::Shifted
+        } else {
+            CharSet::Unshifted
+        };
+        let pattern = match encoding {
+            "ascii" => Ok(BytePattern::text(text)),
+            "petscii" => BytePattern::petscii(text, set),
+            "screen" => BytePattern::screen_codes(text, set),
+            _ => Err(format!("Unknown text encoding '{}'", encoding)),
+        }
+        .map_err(|e| JsError::new(&e))?;
+        Ok(scan(&self.cpu.memory, &pattern, start..=end)
+            .take(max_results as usize)
+            .collect())
+    }
+
+    /// Keep the addresses from an earlier search where a hex byte pattern
+    /// still matches current memory
+    pub fn refine_memory(&self, pattern: &str, addresses: Vec<u16>) -> Result<Vec<u16>, JsError> {
+        let pattern = BytePattern::parse(pattern).map_err(|e| JsError::new(&e))?;
+        Ok(refine(&self.cpu.memory, &pattern, addresses).collect())
     }
 
     /// Load a program into memory and set PC
@@ -394,7 +453,7 @@ impl Emulator6502 {
     /// Disassemble memory starting at an address
     pub fn disassemble(&self, start_addr: u16, num_instructions: u32) -> Vec<JsValue> {
         let memory_vec: Vec<u8> = (0..=0xFFFF)
-            .map(|addr| self.cpu.memory.read(addr))
+            .map(|addr| self.cpu.memory.peek(addr))
             .collect();
 
         let opts = DisassemblyOptions {
@@ -440,4 +499,36 @@ impl Emulator6502 {
     pub fn program_end(&self) -> u16 {
         self.program_end
     }
+
+    /// Run one machine-code monitor command and return its output
+    ///
+    /// Breakpoints persist between calls. See `debugger::monitor` for the
+    /// command set.
+    pub fn monitor_command(&mut self, command: &str) -> String {
+        self.monitor.execute(&mut self.cpu, command)
+    }
+
+    /// Run a program started with the monitor's `g` command for up to
+    /// `max_instructions`
+    ///
+    /// Returns the stop output to show, or `undefined` if the program is
+    /// still running. Call it from an animation frame until it stops.
+    pub fn monitor_run(&mut self, max_instructions: u32) -> Option<String> {
+        self.monitor.run(&mut self.cpu, max_instructions as u64)
+    }
+
+    /// Pass bytes received from a GDB client and return the bytes to send back
+    ///
+    /// The transport (e.g. a WebSocket bridge) is up to the host page.
+    pub fn gdb_feed(&mut self, bytes: &[u8]) -> Vec<u8> {
+        self.gdb.feed(&mut self.cpu, bytes)
+    }
+
+    /// Run a continuing GDB session for up to `max_instructions`
+    ///
+    /// Returns the stop reply to forward to the client, or an empty array if
+    /// the target is still running.
+    pub fn gdb_run(&mut self, max_instructions: u32) -> Vec<u8> {
+        self.gdb.run(&mut self.cpu, max_instructions as u64)
+    }
 }
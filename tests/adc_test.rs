@@ -636,7 +636,7 @@ fn test_adc_decimal_mode_high_nibble_adjust() {
 
     assert_eq!(cpu.a(), 0x00); // BCD result: 100 mod 100 = 00
     assert!(cpu.flag_c()); // Carry set (result >= 100)
-    assert!(cpu.flag_z()); // Zero flag should be set
+    assert!(!cpu.flag_z()); // NMOS: Z follows the binary sum ($9A)
 }
 
 #[test]
@@ -675,7 +675,7 @@ fn test_adc_decimal_mode_99_plus_1() {
 
     assert_eq!(cpu.a(), 0x00); // BCD result: 100 mod 100 = 00
     assert!(cpu.flag_c()); // Carry set
-    assert!(cpu.flag_z()); // Zero flag set
+    assert!(!cpu.flag_z()); // NMOS: Z follows the binary sum ($9A)
 }
 
 #[test]
@@ -724,3 +724,64 @@ fn test_adc_decimal_vs_binary_mode() {
     cpu.step().unwrap();
     assert_eq!(cpu.a(), 0x18); // BCD result: 09 + 09 = 18
 }
+
+#[test]
+fn test_adc_decimal_mode_nmos_negative_and_overflow() {
+    let mut cpu = setup_cpu();
+
+    // ADC #$10
+    cpu.memory_mut().write(0x8000, 0x69);
+    cpu.memory_mut().write(0x8001, 0x10);
+
+    cpu.set_a(0x79);
+    cpu.set_flag_c(false);
+    cpu.set_flag_d(true);
+
+    cpu.step().unwrap();
+
+    // 79 + 10 = 89 BCD; NMOS sets N and V from the intermediate high nibble
+    assert_eq!(cpu.a(), 0x89);
+    assert!(!cpu.flag_c());
+    assert!(cpu.flag_n());
+    assert!(cpu.flag_v());
+    assert!(!cpu.flag_z());
+}
+
+#[test]
+fn test_adc_decimal_mode_invalid_bcd_operands() {
+    let mut cpu = setup_cpu();
+
+    // ADC #$0F
+    cpu.memory_mut().write(0x8000, 0x69);
+    cpu.memory_mut().write(0x8001, 0x0F);
+
+    cpu.set_a(0x0F);
+    cpu.set_flag_c(false);
+    cpu.set_flag_d(true);
+
+    cpu.step().unwrap();
+
+    // Non-BCD digits: low nibble 1E adjusts to 14 with no high-nibble fixup
+    assert_eq!(cpu.a(), 0x14);
+    assert!(!cpu.flag_c());
+}
+
+#[test]
+fn test_adc_decimal_mode_disabled_uses_binary() {
+    let mut cpu = setup_cpu();
+
+    // ADC #$09
+    cpu.memory_mut().write(0x8000, 0x69);
+    cpu.memory_mut().write(0x8001, 0x09);
+
+    assert!(cpu.decimal_mode_enabled());
+    cpu.set_decimal_mode_enabled(false);
+    cpu.set_a(0x09);
+    cpu.set_flag_c(false);
+    cpu.set_flag_d(true);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.a(), 0x12); // Binary result, D flag ignored
+    assert!(cpu.flag_d()); // D flag itself is untouched
+}
@@ -8,6 +8,7 @@
 //! - B flag set in pushed status (but not in CPU flag)
 //! - Correct cycle count (7 cycles)
 //! - PC+2 pushed to stack (not PC+1)
+//! - NMI hijacking (BRK uses the NMI vector)
 
 use lib6502::{FlatMemory, MemoryBus, CPU};
 
@@ -412,3 +413,31 @@ fn test_brk_at_end_of_memory() {
     // PC should wrap: 0xFFFE + 2 = 0x0000, then load from IRQ vector
     assert_eq!(cpu.pc(), 0x8000);
 }
+
+// ========== NMI Hijacking Tests ==========
+
+#[test]
+fn test_brk_hijacked_by_pending_nmi() {
+    let mut cpu = setup_cpu();
+
+    // IRQ vector -> 0x9000, NMI vector -> 0xA000
+    cpu.memory_mut().write(0xFFFE, 0x00);
+    cpu.memory_mut().write(0xFFFF, 0x90);
+    cpu.memory_mut().write(0xFFFA, 0x00);
+    cpu.memory_mut().write(0xFFFB, 0xA0);
+    cpu.memory_mut().write(0x8000, 0x00); // BRK
+
+    cpu.trigger_nmi();
+    cpu.step().unwrap();
+
+    // BRK vectors through NMI and the NMI is consumed
+    assert_eq!(cpu.pc(), 0xA000);
+    assert!(!cpu.nmi_pending());
+    assert_eq!(cpu.cycles(), 7);
+
+    // Only one stack frame, with B set and return address PC+2
+    assert_eq!(cpu.sp(), 0xFA);
+    assert_eq!(cpu.memory_mut().read(0x01FD), 0x80);
+    assert_eq!(cpu.memory_mut().read(0x01FC), 0x02);
+    assert_ne!(cpu.memory_mut().read(0x01FB) & 0x10, 0);
+}
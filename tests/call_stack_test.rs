@@ -0,0 +1,311 @@
+//! Tests for the debugger call stack helpers.
+//!
+//! Tests cover:
+//! - Heuristic stack walking through nested JSRs with data on the stack
+//! - Shadow call stack tracking via CallTracer
+//! - Call/return events and per-subroutine cycle attribution
+//! - Fake returns (jump tables) inside a call leave the shadow stack intact
+//! - Hardware interrupt detection while tracing
+//! - TAS/LAS stack pointer loads are not mistaken for interrupts
+
+use lib6502::debugger::{walk_stack, CallEvent, CallKind, CallTracer};
+use lib6502::{FlatMemory, MemoryBus, CPU};
+
+/// Helper function to create a CPU with reset vector at 0x8000
+fn setup_cpu() -> CPU<FlatMemory> {
+    let mut memory = FlatMemory::new();
+    memory.write(0xFFFC, 0x00);
+    memory.write(0xFFFD, 0x80);
+    CPU::new(memory)
+}
+
+/// Loads a program with two levels of subroutine calls:
+///
+/// ```text
+/// $8000: JSR $9000
+/// $8003: NOP
+/// $9000: LDA #$AA
+/// $9002: PHA          ; data byte between the two return addresses
+/// $9003: JSR $A000
+/// $9006: PLA
+/// $9007: RTS
+/// $A000: NOP
+/// $A001: RTS
+/// ```
+fn load_nested_program(cpu: &mut CPU<FlatMemory>) {
+    let program: &[(u16, &[u8])] = &[
+        (0x8000, &[0x20, 0x00, 0x90, 0xEA]),
+        (0x9000, &[0xA9, 0xAA, 0x48, 0x20, 0x00, 0xA0, 0x68, 0x60]),
+        (0xA000, &[0xEA, 0x60]),
+    ];
+    for (base, bytes) in program {
+        for (i, byte) in bytes.iter().enumerate() {
+            cpu.memory_mut().write(base + i as u16, *byte);
+        }
+    }
+}
+
+// ========== walk_stack Tests ==========
+
+#[test]
+fn test_walk_stack_empty() {
+    let cpu = setup_cpu();
+    assert!(walk_stack(&cpu).is_empty());
+}
+
+#[test]
+fn test_walk_stack_nested_calls_skip_data() {
+    let mut cpu = setup_cpu();
+    load_nested_program(&mut cpu);
+
+    // JSR, LDA, PHA, JSR -> now inside $A000
+    for _ in 0..4 {
+        cpu.step().unwrap();
+    }
+    assert_eq!(cpu.pc(), 0xA000);
+
+    let frames = walk_stack(&cpu);
+    assert_eq!(frames.len(), 2);
+
+    // Innermost first
+    assert_eq!(frames[0].call_site, 0x9003);
+    assert_eq!(frames[0].target, 0xA000);
+    assert_eq!(frames[0].return_addr, 0x9006);
+    assert_eq!(frames[0].stack_addr, 0x0100 | (cpu.sp() as u16 + 1));
+
+    assert_eq!(frames[1].call_site, 0x8000);
+    assert_eq!(frames[1].target, 0x9000);
+    assert_eq!(frames[1].return_addr, 0x8003);
+}
+
+// ========== CallTracer Tests ==========
+
+#[test]
+fn test_tracer_shadow_stack_follows_calls() {
+    let mut cpu = setup_cpu();
+    load_nested_program(&mut cpu);
+    let mut tracer = CallTracer::new();
+
+    for _ in 0..4 {
+        tracer.step(&mut cpu).unwrap();
+    }
+
+    let stack = tracer.call_stack();
+    assert_eq!(stack.len(), 2);
+    assert_eq!(stack[0].target, 0x9000);
+    assert_eq!(stack[1].target, 0xA000);
+    assert_eq!(stack[1].call_site, 0x9003);
+    assert_eq!(stack[1].kind, CallKind::Subroutine);
+
+    // NOP, RTS, PLA, RTS -> back in main program
+    for _ in 0..4 {
+        tracer.step(&mut cpu).unwrap();
+    }
+    assert_eq!(cpu.pc(), 0x8003);
+    assert!(tracer.call_stack().is_empty());
+}
+
+#[test]
+fn test_tracer_events_and_profile() {
+    let mut cpu = setup_cpu();
+    load_nested_program(&mut cpu);
+    let mut tracer = CallTracer::new();
+
+    for _ in 0..8 {
+        tracer.step(&mut cpu).unwrap();
+    }
+
+    let events = tracer.take_events();
+    assert_eq!(events.len(), 4);
+    assert_eq!(
+        events[0],
+        CallEvent::Call {
+            kind: CallKind::Subroutine,
+            call_site: 0x8000,
+            target: 0x9000,
+            cycle: 0,
+        }
+    );
+
+    // Inner call: JSR (6) + NOP (2) + RTS (6)
+    match events[2] {
+        CallEvent::Return {
+            target, elapsed, ..
+        } => {
+            assert_eq!(target, 0xA000);
+            assert_eq!(elapsed, 14);
+        }
+        other => panic!("Expected inner return, got {:?}", other),
+    }
+
+    // Outer call includes everything: JSR, LDA, PHA, inner call, PLA, RTS
+    let outer = tracer.profile()[&0x9000];
+    assert_eq!(outer.calls, 1);
+    assert_eq!(outer.cycles, 6 + 2 + 3 + 14 + 4 + 6);
+    assert_eq!(outer.cycles, cpu.cycles());
+
+    // Events are drained once taken
+    assert!(tracer.take_events().is_empty());
+}
+
+#[test]
+fn test_tracer_ignores_unmatched_return() {
+    let mut cpu = setup_cpu();
+    let mut tracer = CallTracer::new();
+
+    // Push a fake return address and RTS to it
+    // $8000: LDA #$90, PHA, LDA #$FF, PHA, RTS
+    let program = [0xA9, 0x90, 0x48, 0xA9, 0xFF, 0x48, 0x60];
+    for (i, byte) in program.iter().enumerate() {
+        cpu.memory_mut().write(0x8000 + i as u16, *byte);
+    }
+
+    for _ in 0..5 {
+        tracer.step(&mut cpu).unwrap();
+    }
+
+    assert_eq!(cpu.pc(), 0x9100);
+    assert!(tracer.take_events().is_empty());
+    assert!(tracer.profile().is_empty());
+}
+
+#[test]
+fn test_tracer_ignores_jump_table_rts_inside_call() {
+    let mut cpu = setup_cpu();
+    let mut tracer = CallTracer::new();
+
+    // $8000: JSR $9000
+    // $9000: LDA #$A0, PHA, LDA #$FF, PHA, RTS   ; jumps to $A100
+    // $A100: RTS                                 ; real return to $8003
+    let program: &[(u16, &[u8])] = &[
+        (0x8000, &[0x20, 0x00, 0x90]),
+        (0x9000, &[0xA9, 0xA0, 0x48, 0xA9, 0xFF, 0x48, 0x60]),
+        (0xA100, &[0x60]),
+    ];
+    for (base, bytes) in program {
+        for (i, byte) in bytes.iter().enumerate() {
+            cpu.memory_mut().write(base + i as u16, *byte);
+        }
+    }
+
+    for _ in 0..6 {
+        tracer.step(&mut cpu).unwrap();
+    }
+    assert_eq!(cpu.pc(), 0xA100);
+    assert_eq!(tracer.call_stack().len(), 1);
+    assert_eq!(tracer.call_stack()[0].target, 0x9000);
+    assert_eq!(tracer.call_stack()[0].stack_pointer, 0xFB);
+    assert_eq!(tracer.take_events().len(), 1); // Only the JSR
+
+    tracer.step(&mut cpu).unwrap();
+    assert_eq!(cpu.pc(), 0x8003);
+    assert!(tracer.call_stack().is_empty());
+    assert!(matches!(
+        tracer.take_events()[..],
+        [CallEvent::Return {
+            kind: CallKind::Subroutine,
+            target: 0x9000,
+            ..
+        }]
+    ));
+}
+
+// ========== Interrupt Tracing Tests ==========
+
+/// Flat memory with a manually controlled IRQ line.
+struct IrqMemory {
+    data: Vec<u8>,
+    irq: bool,
+}
+
+impl MemoryBus for IrqMemory {
+    fn read(&self, addr: u16) -> u8 {
+        self.data[addr as usize]
+    }
+
+    fn write(&mut self, addr: u16, value: u8) {
+        self.data[addr as usize] = value;
+    }
+
+    fn irq_active(&self) -> bool {
+        self.irq
+    }
+}
+
+#[test]
+fn test_tracer_detects_hardware_interrupt() {
+    let mut memory = IrqMemory {
+        data: vec![0; 0x10000],
+        irq: false,
+    };
+    memory.write(0xFFFC, 0x00);
+    memory.write(0xFFFD, 0x80);
+    memory.write(0xFFFE, 0x00);
+    memory.write(0xFFFF, 0xC0);
+
+    // $8000: CLI, JSR $9000 ; $9000: NOP ; $C000: RTI
+    for (i, byte) in [0x58, 0x20, 0x00, 0x90].iter().enumerate() {
+        memory.write(0x8000 + i as u16, *byte);
+    }
+    memory.write(0x9000, 0xEA);
+    memory.write(0xC000, 0x40);
+
+    let mut cpu = CPU::new(memory);
+    let mut tracer = CallTracer::new();
+
+    tracer.step(&mut cpu).unwrap(); // CLI
+
+    // IRQ arrives during the JSR and is serviced right after it
+    cpu.memory_mut().irq = true;
+    tracer.step(&mut cpu).unwrap();
+    cpu.memory_mut().irq = false;
+    assert_eq!(cpu.pc(), 0xC000);
+
+    let stack = tracer.call_stack();
+    assert_eq!(stack.len(), 2);
+    assert_eq!(stack[0].kind, CallKind::Subroutine);
+    assert_eq!(stack[1].kind, CallKind::Interrupt);
+    assert_eq!(stack[1].call_site, 0x9000); // Interrupted before the NOP
+    assert_eq!(stack[1].target, 0xC000);
+    assert_eq!(stack[1].start_cycle, 2 + 6);
+
+    tracer.step(&mut cpu).unwrap(); // RTI
+    assert_eq!(cpu.pc(), 0x9000);
+    assert_eq!(tracer.call_stack().len(), 1);
+
+    let handler = tracer.profile()[&0xC000];
+    assert_eq!(handler.calls, 1);
+    assert_eq!(handler.cycles, 7 + 6); // Interrupt entry + RTI
+}
+
+#[test]
+fn test_tracer_follows_undocumented_stack_loads() {
+    let mut cpu = setup_cpu();
+    let mut tracer = CallTracer::new();
+
+    // $8000: LDA #$FA, LDX #$FA, TAS $2000,Y   ; SP = $FA
+    // $8007: LDA #$FF, LDX #$FF, TAS $2000,Y   ; SP = $FF
+    // $800E: LAS $2100,Y                       ; SP = $FC & $FF
+    let program = [
+        0xA9, 0xFA, 0xA2, 0xFA, 0x9B, 0x00, 0x20, 0xA9, 0xFF, 0xA2, 0xFF, 0x9B, 0x00, 0x20, 0xBB,
+        0x00, 0x21,
+    ];
+    for (i, byte) in program.iter().enumerate() {
+        cpu.memory_mut().write(0x8000 + i as u16, *byte);
+    }
+    cpu.memory_mut().write(0x2100, 0xFC);
+
+    // Each stack load drops SP by exactly three, which must not be mistaken
+    // for an interrupt frame
+    for _ in 0..3 {
+        tracer.step(&mut cpu).unwrap();
+    }
+    assert_eq!(cpu.sp(), 0xFA);
+    assert!(tracer.call_stack().is_empty());
+
+    for _ in 0..4 {
+        tracer.step(&mut cpu).unwrap();
+    }
+    assert_eq!(cpu.sp(), 0xFC);
+    assert!(tracer.call_stack().is_empty());
+}
@@ -64,7 +64,7 @@ fn test_multi_instruction_disassembly() {
 fn test_illegal_opcode_handling() {
     let bytes = &[
         0xA9, 0x42, // LDA #$42 (valid)
-        0xFF, // Illegal opcode
+        0x02, // JAM opcode (not executable)
         0xEA, // NOP (valid)
     ];
 
@@ -78,8 +78,8 @@ fn test_illegal_opcode_handling() {
     // Illegal opcode should be represented as .byte
     assert_eq!(instructions[1].address, 0x0002);
     assert_eq!(instructions[1].mnemonic, ".byte");
-    assert_eq!(instructions[1].opcode, 0xFF);
-    assert_eq!(format_instruction(&instructions[1]), ".byte $FF");
+    assert_eq!(instructions[1].opcode, 0x02);
+    assert_eq!(format_instruction(&instructions[1]), ".byte $02");
 
     // NOP is valid
     assert_eq!(instructions[2].address, 0x0003);
@@ -13,7 +13,7 @@
 //!
 //! ## What This Tests
 //!
-//! - **Disassembler**: Correctly decodes all 151 NMOS 6502 opcodes
+//! - **Disassembler**: Correctly decodes all 151 documented NMOS 6502 opcodes
 //! - **Assembler**: Correctly encodes all instructions and addressing modes
 //! - **Addressing modes**: All 13 addressing modes work correctly
 //! - **Operand encoding**: Immediate, zero page, absolute, indexed, indirect modes
@@ -22,7 +22,7 @@
 //! ## Success Criteria
 //!
 //! If the reassembled binary matches the original Klaus binary byte-for-byte,
-//! both the assembler and disassembler are validated across all 151 opcodes.
+//! both the assembler and disassembler are validated across all 151 documented opcodes.
 
 use lib6502::assembler::assemble;
 use lib6502::disassembler::{disassemble, DisassemblyOptions, Instruction};
@@ -0,0 +1,260 @@
+//! Tests for the debugger GDB remote protocol stub.
+//!
+//! Tests cover:
+//! - Packet framing, acks and checksum errors
+//! - Packets split across several feeds
+//! - Register read/write (g, G, p, P)
+//! - Memory read/write (m, M)
+//! - Breakpoints, stepping, continuing and interrupting
+//! - No-ack mode
+//! - `?` reports the most recent stop reason
+//! - Malformed hex fields and oversized memory reads are rejected
+
+use lib6502::debugger::GdbStub;
+use lib6502::{FlatMemory, MemoryBus, CPU};
+
+/// Helper function to create a CPU with reset vector at 0x8000
+fn setup_cpu() -> CPU<FlatMemory> {
+    let mut memory = FlatMemory::new();
+    memory.write(0xFFFC, 0x00);
+    memory.write(0xFFFD, 0x80);
+    CPU::new(memory)
+}
+
+/// Frame a packet body as `$body#xx`.
+fn packet(body: &str) -> Vec<u8> {
+    let sum = body.bytes().fold(0u8, |sum, b| sum.wrapping_add(b));
+    format!("${}#{:02x}", body, sum).into_bytes()
+}
+
+/// Expected output for an acked packet with the given reply body.
+fn acked_reply(body: &str) -> Vec<u8> {
+    let mut expected = vec![b'+'];
+    expected.extend(packet(body));
+    expected
+}
+
+#[test]
+fn test_bad_checksum_is_nacked() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    assert_eq!(stub.feed(&mut cpu, b"$g#00"), b"-");
+}
+
+#[test]
+fn test_packet_split_across_feeds() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    let bytes = packet("?");
+    assert!(stub.feed(&mut cpu, &bytes[..2]).is_empty());
+    assert_eq!(stub.feed(&mut cpu, &bytes[2..]), acked_reply("S05"));
+}
+
+#[test]
+fn test_leading_acks_are_ignored() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    let mut bytes = b"+".to_vec();
+    bytes.extend(packet("?"));
+    assert_eq!(stub.feed(&mut cpu, &bytes), acked_reply("S05"));
+}
+
+#[test]
+fn test_read_and_write_registers() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    cpu.set_a(0x12);
+    cpu.set_x(0x34);
+    cpu.set_y(0x56);
+    // A X Y P SP PC(lo hi)
+    let status = format!("{:02x}", cpu.status());
+    assert_eq!(
+        stub.feed(&mut cpu, &packet("g")),
+        acked_reply(&format!("123456{}fd0080", status))
+    );
+
+    assert_eq!(
+        stub.feed(&mut cpu, &packet("G01020381f03412")),
+        acked_reply("OK")
+    );
+    assert_eq!(cpu.a(), 0x01);
+    assert_eq!(cpu.x(), 0x02);
+    assert_eq!(cpu.y(), 0x03);
+    assert!(cpu.flag_n() && cpu.flag_c() && !cpu.flag_i());
+    assert_eq!(cpu.sp(), 0xF0);
+    assert_eq!(cpu.pc(), 0x1234);
+
+    assert_eq!(stub.feed(&mut cpu, &packet("p5")), acked_reply("3412"));
+    assert_eq!(stub.feed(&mut cpu, &packet("P0=ff")), acked_reply("OK"));
+    assert_eq!(cpu.a(), 0xFF);
+
+    assert_eq!(stub.feed(&mut cpu, &packet("p6")), acked_reply("E01"));
+    assert_eq!(stub.feed(&mut cpu, &packet("G0102")), acked_reply("E01"));
+}
+
+#[test]
+fn test_read_and_write_memory() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    assert_eq!(
+        stub.feed(&mut cpu, &packet("M2000,3:a1b2c3")),
+        acked_reply("OK")
+    );
+    assert_eq!(cpu.memory().read(0x2001), 0xB2);
+
+    assert_eq!(
+        stub.feed(&mut cpu, &packet("m1fff,5")),
+        acked_reply("00a1b2c300")
+    );
+
+    // Length mismatch
+    assert_eq!(
+        stub.feed(&mut cpu, &packet("M2000,2:a1")),
+        acked_reply("E01")
+    );
+}
+
+#[test]
+fn test_step_and_step_error() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    cpu.memory_mut().write(0x8000, 0xE8); // INX
+    cpu.memory_mut().write(0x8001, 0x02); // JAM (unimplemented)
+
+    assert_eq!(stub.feed(&mut cpu, &packet("s")), acked_reply("S05"));
+    assert_eq!(cpu.x(), 1);
+    assert_eq!(stub.feed(&mut cpu, &packet("s")), acked_reply("S04"));
+
+    // `?` repeats the last stop reason
+    assert_eq!(stub.feed(&mut cpu, &packet("?")), acked_reply("S04"));
+}
+
+#[test]
+fn test_continue_until_breakpoint() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    // $8000: INX / JMP $8000
+    cpu.memory_mut().write(0x8000, 0xE8);
+    cpu.memory_mut().write(0x8001, 0x4C);
+    cpu.memory_mut().write(0x8002, 0x00);
+    cpu.memory_mut().write(0x8003, 0x80);
+
+    assert_eq!(stub.feed(&mut cpu, &packet("Z0,8001,1")), acked_reply("OK"));
+    assert_eq!(stub.breakpoints().collect::<Vec<_>>(), vec![0x8001]);
+
+    // Continue is acked but the stop reply comes from run()
+    assert_eq!(stub.feed(&mut cpu, &packet("c")), b"+");
+    assert!(stub.is_running());
+    assert_eq!(stub.run(&mut cpu, 100), packet("S05"));
+    assert!(!stub.is_running());
+    assert_eq!(cpu.pc(), 0x8001);
+
+    // Continuing from a breakpoint executes it before stopping again
+    stub.feed(&mut cpu, &packet("c"));
+    assert_eq!(stub.run(&mut cpu, 100), packet("S05"));
+    assert_eq!(cpu.x(), 2);
+
+    // Without breakpoints, run() returns nothing until the budget is spent
+    assert_eq!(stub.feed(&mut cpu, &packet("z0,8001,1")), acked_reply("OK"));
+    stub.feed(&mut cpu, &packet("c"));
+    assert!(stub.run(&mut cpu, 100).is_empty());
+    assert!(stub.is_running());
+}
+
+#[test]
+fn test_interrupt_stops_running_target() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    stub.feed(&mut cpu, &packet("c"));
+    assert_eq!(stub.feed(&mut cpu, &[0x03]), packet("S02"));
+    assert!(!stub.is_running());
+
+    // Nothing to interrupt now
+    assert!(stub.feed(&mut cpu, &[0x03]).is_empty());
+    assert_eq!(stub.feed(&mut cpu, &packet("?")), acked_reply("S02"));
+}
+
+#[test]
+fn test_many_packets_in_one_feed() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    // Acks and packets interleaved, with a partial packet left at the end
+    let mut bytes = Vec::new();
+    let mut expected = Vec::new();
+    for _ in 0..1000 {
+        bytes.push(b'+');
+        bytes.extend(packet("?"));
+        expected.extend(acked_reply("S05"));
+    }
+    bytes.extend(&packet("?")[..3]);
+
+    assert_eq!(stub.feed(&mut cpu, &bytes), expected);
+    assert_eq!(stub.feed(&mut cpu, &packet("?")[3..]), acked_reply("S05"));
+}
+
+#[test]
+fn test_no_ack_mode_and_queries() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    let supported = stub.feed(&mut cpu, &packet("qSupported:multiprocess+"));
+    assert_eq!(supported, acked_reply("PacketSize=1000;QStartNoAckMode+"));
+
+    assert_eq!(
+        stub.feed(&mut cpu, &packet("QStartNoAckMode")),
+        acked_reply("OK")
+    );
+    assert_eq!(stub.feed(&mut cpu, &packet("qAttached")), packet("1"));
+
+    // Unsupported packets get an empty reply
+    assert_eq!(stub.feed(&mut cpu, &packet("vMustReplyEmpty")), packet(""));
+}
+
+#[test]
+fn test_rejects_malformed_hex_fields() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    // from_str_radix alone would accept the leading '+'
+    for body in [
+        "m+1,2",
+        "m2000,+2",
+        "s+8000",
+        "c+8000",
+        "Z0,+8000,1",
+        "p+1",
+        "P+1=00",
+    ] {
+        assert_eq!(
+            stub.feed(&mut cpu, &packet(body)),
+            acked_reply("E01"),
+            "{}",
+            body
+        );
+    }
+    assert_eq!(
+        stub.feed(&mut cpu, &packet("M2000,1:+1")),
+        acked_reply("E01")
+    );
+    assert_eq!(cpu.pc(), 0x8000);
+    assert_eq!(stub.breakpoints().count(), 0);
+}
+
+#[test]
+fn test_memory_read_fits_in_packet() {
+    let mut cpu = setup_cpu();
+    let mut stub = GdbStub::new();
+
+    let reply = stub.feed(&mut cpu, &packet("m0,800"));
+    assert_eq!(reply.len(), acked_reply(&"00".repeat(0x800)).len());
+    assert_eq!(stub.feed(&mut cpu, &packet("m0,801")), acked_reply("E01"));
+}
@@ -0,0 +1,451 @@
+//! Tests for the undocumented NMOS 6502 opcodes.
+//!
+//! Tests cover:
+//! - Combined read-modify-write opcodes (SLO, RLA, SRE, RRA, DCP, ISC)
+//! - Combined loads and stores (LAX, SAX, LAS)
+//! - Immediate opcodes (ANC, ALR, ARR, XAA, AXS, SBC $EB)
+//! - Unstable high-byte stores (SHA, SHX, SHY, TAS)
+//! - NOP variants with operands, and JAM opcodes staying unimplemented
+//! - Assembler and disassembler support for the illegal mnemonics
+//! - Illegal duplicates of other encodings disassembling as `.byte`
+
+use lib6502::disassembler::formatter::format_instruction;
+use lib6502::{
+    assemble, disassemble, DisassemblyOptions, ExecutionError, FlatMemory, MemoryBus, CPU,
+};
+
+/// Helper function to create a CPU with reset vector at 0x8000
+fn setup_cpu() -> CPU<FlatMemory> {
+    let mut memory = FlatMemory::new();
+    memory.write(0xFFFC, 0x00);
+    memory.write(0xFFFD, 0x80);
+    CPU::new(memory)
+}
+
+/// Helper to write a program at 0x8000
+fn load_program(cpu: &mut CPU<FlatMemory>, bytes: &[u8]) {
+    for (i, byte) in bytes.iter().enumerate() {
+        cpu.memory_mut().write(0x8000 + i as u16, *byte);
+    }
+}
+
+// ========== Read-Modify-Write Combinations ==========
+
+#[test]
+fn test_slo_zero_page() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x07, 0x10]); // SLO $10
+    cpu.memory_mut().write(0x0010, 0x81);
+    cpu.set_a(0x02);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.memory_mut().read(0x0010), 0x02);
+    assert_eq!(cpu.a(), 0x02);
+    assert!(cpu.flag_c()); // Old bit 7
+    assert!(!cpu.flag_z());
+    assert!(!cpu.flag_n());
+    assert_eq!(cpu.pc(), 0x8002);
+    assert_eq!(cpu.cycles(), 5);
+}
+
+#[test]
+fn test_rla_absolute() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x2F, 0x00, 0x20]); // RLA $2000
+    cpu.memory_mut().write(0x2000, 0x40);
+    cpu.set_a(0xFF);
+    cpu.set_flag_c(true);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.memory_mut().read(0x2000), 0x81);
+    assert_eq!(cpu.a(), 0x81);
+    assert!(!cpu.flag_c());
+    assert!(cpu.flag_n());
+    assert_eq!(cpu.cycles(), 6);
+}
+
+#[test]
+fn test_sre_zero_page_x() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x57, 0x10]); // SRE $10,X
+    cpu.memory_mut().write(0x0015, 0x03);
+    cpu.set_x(0x05);
+    cpu.set_a(0x01);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.memory_mut().read(0x0015), 0x01);
+    assert_eq!(cpu.a(), 0x00);
+    assert!(cpu.flag_c());
+    assert!(cpu.flag_z());
+    assert_eq!(cpu.cycles(), 6);
+}
+
+#[test]
+fn test_rra_uses_carry_from_rotate() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x67, 0x10]); // RRA $10
+    cpu.memory_mut().write(0x0010, 0x03);
+    cpu.set_a(0x10);
+    cpu.set_flag_c(false);
+
+    cpu.step().unwrap();
+
+    // ROR: $03 -> $01, C = 1; ADC: $10 + $01 + 1 = $12
+    assert_eq!(cpu.memory_mut().read(0x0010), 0x01);
+    assert_eq!(cpu.a(), 0x12);
+    assert!(!cpu.flag_c());
+}
+
+#[test]
+fn test_dcp_compares_decremented_value() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0xC7, 0x10]); // DCP $10
+    cpu.memory_mut().write(0x0010, 0x43);
+    cpu.set_a(0x42);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.memory_mut().read(0x0010), 0x42);
+    assert_eq!(cpu.a(), 0x42); // A unchanged
+    assert!(cpu.flag_z());
+    assert!(cpu.flag_c());
+}
+
+#[test]
+fn test_isc_indirect_y_cycles() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0xF3, 0x20]); // ISC ($20),Y
+    cpu.memory_mut().write(0x0020, 0xFF);
+    cpu.memory_mut().write(0x0021, 0x20);
+    cpu.memory_mut().write(0x2100, 0x04);
+    cpu.set_y(0x01);
+    cpu.set_a(0x10);
+    cpu.set_flag_c(true);
+
+    cpu.step().unwrap();
+
+    // INC: $04 -> $05; SBC: $10 - $05 = $0B
+    assert_eq!(cpu.memory_mut().read(0x2100), 0x05);
+    assert_eq!(cpu.a(), 0x0B);
+    assert!(cpu.flag_c());
+    // No page crossing penalty for read-modify-write opcodes
+    assert_eq!(cpu.cycles(), 8);
+}
+
+// ========== Loads and Stores ==========
+
+#[test]
+fn test_lax_loads_a_and_x() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0xA7, 0x10]); // LAX $10
+    cpu.memory_mut().write(0x0010, 0x80);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.a(), 0x80);
+    assert_eq!(cpu.x(), 0x80);
+    assert!(cpu.flag_n());
+    assert_eq!(cpu.cycles(), 3);
+}
+
+#[test]
+fn test_lax_absolute_y_page_crossing() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0xBF, 0xFF, 0x20]); // LAX $20FF,Y
+    cpu.memory_mut().write(0x2100, 0x33);
+    cpu.set_y(0x01);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.a(), 0x33);
+    assert_eq!(cpu.x(), 0x33);
+    assert_eq!(cpu.cycles(), 5); // 4 + 1 for page crossing
+}
+
+#[test]
+fn test_sax_stores_a_and_x() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x87, 0x10]); // SAX $10
+    cpu.set_a(0xF0);
+    cpu.set_x(0x3C);
+    cpu.set_flag_z(false);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.memory_mut().read(0x0010), 0x30);
+    assert!(!cpu.flag_z()); // Flags unaffected
+    assert_eq!(cpu.cycles(), 3);
+}
+
+#[test]
+fn test_las_ands_with_stack_pointer() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0xBB, 0x00, 0x20]); // LAS $2000,Y
+    cpu.memory_mut().write(0x2000, 0x0F);
+    cpu.set_sp(0xFD);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.a(), 0x0D);
+    assert_eq!(cpu.x(), 0x0D);
+    assert_eq!(cpu.sp(), 0x0D);
+    assert_eq!(cpu.cycles(), 4);
+}
+
+// ========== Immediate Opcodes ==========
+
+#[test]
+fn test_anc_copies_negative_into_carry() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x0B, 0xF0]); // ANC #$F0
+    cpu.set_a(0x81);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.a(), 0x80);
+    assert!(cpu.flag_n());
+    assert!(cpu.flag_c());
+}
+
+#[test]
+fn test_alr_ands_then_shifts() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x4B, 0x03]); // ALR #$03
+    cpu.set_a(0xFF);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.a(), 0x01);
+    assert!(cpu.flag_c());
+    assert!(!cpu.flag_z());
+}
+
+#[test]
+fn test_arr_binary_flags() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x6B, 0xFF]); // ARR #$FF
+    cpu.set_a(0x80);
+    cpu.set_flag_c(true);
+
+    cpu.step().unwrap();
+
+    // ($80 >> 1) | $80 = $C0: C = bit 6, V = bit 6 ^ bit 5
+    assert_eq!(cpu.a(), 0xC0);
+    assert!(cpu.flag_n());
+    assert!(cpu.flag_c());
+    assert!(cpu.flag_v());
+}
+
+#[test]
+fn test_arr_decimal_mode_adjusts_result() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x6B, 0xFF]); // ARR #$FF
+    cpu.set_a(0xDB);
+    cpu.set_flag_c(false);
+    cpu.set_flag_d(true);
+
+    cpu.step().unwrap();
+
+    // ROR gives $6D; both nibbles are then BCD-adjusted
+    assert_eq!(cpu.a(), 0xC3);
+    assert!(cpu.flag_c());
+    assert!(!cpu.flag_n());
+}
+
+#[test]
+fn test_xaa_uses_unstable_constant() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x8B, 0xFF]); // XAA #$FF
+    cpu.set_a(0x00);
+    cpu.set_x(0x0F);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.a(), 0x0E); // ($00 | $EE) & $0F & $FF
+}
+
+#[test]
+fn test_axs_subtracts_without_borrow() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0xCB, 0x05]); // AXS #$05
+    cpu.set_a(0x0F);
+    cpu.set_x(0x06);
+    cpu.set_flag_c(false); // Incoming carry is ignored
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.x(), 0x01);
+    assert!(cpu.flag_c());
+    assert_eq!(cpu.a(), 0x0F);
+}
+
+#[test]
+fn test_sbc_eb_matches_documented_sbc() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0xEB, 0x01]); // SBC #$01 (illegal encoding)
+    cpu.set_a(0x05);
+    cpu.set_flag_c(true);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.a(), 0x04);
+    assert!(cpu.flag_c());
+    assert_eq!(cpu.cycles(), 2);
+}
+
+// ========== Unstable Stores ==========
+
+#[test]
+fn test_shy_stores_y_and_high_byte_plus_one() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x9C, 0x00, 0x12]); // SHY $1200,X
+    cpu.set_y(0xFF);
+    cpu.set_x(0x05);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.memory_mut().read(0x1205), 0x13);
+    assert_eq!(cpu.cycles(), 5);
+}
+
+#[test]
+fn test_shx_page_crossing_corrupts_address() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x9E, 0xFF, 0x12]); // SHX $12FF,Y
+    cpu.set_x(0x02);
+    cpu.set_y(0x01);
+
+    cpu.step().unwrap();
+
+    // Value is X & $13 = $02, and it also replaces the target high byte
+    assert_eq!(cpu.memory_mut().read(0x0200), 0x02);
+    assert_eq!(cpu.memory_mut().read(0x1300), 0x00);
+}
+
+#[test]
+fn test_sha_indirect_y() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x93, 0x20]); // SHA ($20),Y
+    cpu.memory_mut().write(0x0020, 0x00);
+    cpu.memory_mut().write(0x0021, 0x30);
+    cpu.set_a(0xFF);
+    cpu.set_x(0xF1);
+    cpu.set_y(0x10);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.memory_mut().read(0x3010), 0x31); // $FF & $F1 & $31
+    assert_eq!(cpu.cycles(), 6);
+}
+
+#[test]
+fn test_tas_sets_stack_pointer() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x9B, 0x00, 0x30]); // TAS $3000,Y
+    cpu.set_a(0xF3);
+    cpu.set_x(0x3F);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.sp(), 0x33);
+    assert_eq!(cpu.memory_mut().read(0x3000), 0x31); // $33 & $31
+}
+
+// ========== NOP Variants and JAM ==========
+
+#[test]
+fn test_nop_variants_skip_operands() {
+    let mut cpu = setup_cpu();
+    load_program(
+        &mut cpu,
+        &[
+            0x1A, // NOP
+            0x80, 0x42, // NOP #$42
+            0x04, 0x10, // NOP $10
+            0x14, 0x10, // NOP $10,X
+            0x0C, 0x00, 0x20, // NOP $2000
+        ],
+    );
+
+    for _ in 0..5 {
+        cpu.step().unwrap();
+    }
+
+    assert_eq!(cpu.pc(), 0x800A);
+    assert_eq!(cpu.cycles(), 2 + 2 + 3 + 4 + 4);
+    assert_eq!(cpu.a(), 0x00);
+}
+
+#[test]
+fn test_nop_absolute_x_page_crossing() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x1C, 0xFF, 0x20]); // NOP $20FF,X
+    cpu.set_x(0x01);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.pc(), 0x8003);
+    assert_eq!(cpu.cycles(), 5);
+}
+
+#[test]
+fn test_jam_opcodes_remain_unimplemented() {
+    let mut cpu = setup_cpu();
+    load_program(&mut cpu, &[0x02]);
+
+    assert_eq!(cpu.step(), Err(ExecutionError::UnimplementedOpcode(0x02)));
+}
+
+// ========== Assembler and Disassembler ==========
+
+#[test]
+fn test_assemble_illegal_mnemonics() {
+    let output = assemble("LAX $10\nSAX $2000\nDCP ($20),Y\nAXS #$05").unwrap();
+
+    assert_eq!(
+        output.bytes,
+        vec![0xA7, 0x10, 0x8F, 0x00, 0x20, 0xD3, 0x20, 0xCB, 0x05]
+    );
+}
+
+#[test]
+fn test_assemble_prefers_documented_encoding() {
+    let output = assemble("NOP\nSBC #$01").unwrap();
+
+    assert_eq!(output.bytes, vec![0xEA, 0xE9, 0x01]);
+}
+
+#[test]
+fn test_disassemble_illegal_opcodes() {
+    let bytes = &[0xA7, 0x10, 0x04, 0x20, 0xCB, 0x05];
+    let instructions = disassemble(bytes, DisassemblyOptions::default());
+
+    let mnemonics: Vec<&str> = instructions.iter().map(|i| i.mnemonic).collect();
+    assert_eq!(mnemonics, vec!["LAX", "NOP", "AXS"]);
+}
+
+#[test]
+fn test_illegal_duplicates_disassemble_as_bytes() {
+    // NOP $1A (dup of $EA), NOP zp,X $54 (dup of $14), SBC # $EB (dup of $E9)
+    let bytes = [0x1A, 0x54, 0x02, 0xEB, 0x14, 0x02];
+    let instructions = disassemble(&bytes, DisassemblyOptions::default());
+
+    let lines: Vec<String> = instructions.iter().map(format_instruction).collect();
+    assert_eq!(
+        lines,
+        vec![
+            ".byte $1A",
+            ".byte $54",
+            ".byte $02",
+            ".byte $EB",
+            "NOP $02,X"
+        ]
+    );
+
+    // The listing reassembles byte-for-byte
+    let output = assemble(&lines.join("\n")).unwrap();
+    assert_eq!(output.bytes, bytes);
+}
@@ -6,6 +6,7 @@
 //! - I flag respect (interrupts disabled when I flag set)
 //! - Device interrupt acknowledgment
 //! - Multiple device coordination
+//! - Edge-triggered NMI and NMI/IRQ priority
 
 use lib6502::{Device, MappedMemory, MemoryBus, RamDevice, CPU};
 use std::any::Any;
@@ -46,13 +47,7 @@ impl MockInterruptDevice {
 impl Device for MockInterruptDevice {
     fn read(&self, offset: u16) -> u8 {
         match offset {
-            Self::STATUS_REG => {
-                if self.interrupt_pending {
-                    1 << Self::INTERRUPT_PENDING_BIT
-                } else {
-                    0
-                }
-            }
+            Self::STATUS_REG if self.interrupt_pending => 1 << Self::INTERRUPT_PENDING_BIT,
             _ => 0x00,
         }
     }
@@ -720,13 +715,7 @@ impl SharedMockDevice {
 impl Device for SharedMockDevice {
     fn read(&self, offset: u16) -> u8 {
         match offset {
-            0 => {
-                if self.interrupt_pending {
-                    0x80
-                } else {
-                    0
-                }
-            }
+            0 if self.interrupt_pending => 0x80,
             1 => self.data,
             _ => 0x00,
         }
@@ -734,10 +723,8 @@ impl Device for SharedMockDevice {
 
     fn write(&mut self, offset: u16, value: u8) {
         match offset {
-            0 => {
-                if value & 0x80 != 0 {
-                    self.interrupt_pending = false;
-                }
+            0 if value & 0x80 != 0 => {
+                self.interrupt_pending = false;
             }
             1 => self.data = value,
             _ => {}
@@ -868,3 +855,235 @@ fn test_get_device_at_returns_none_for_shared() {
         "Should return None for shared device (users keep their Rc handle)"
     );
 }
+
+// ========== I Flag Change Timing ==========
+
+/// Create a test CPU with an interrupt device at 0xD000 already asserting IRQ
+/// and an RTI handler at 0xC000.
+fn create_cpu_with_pending_irq() -> CPU<MappedMemory> {
+    let mut cpu = create_test_cpu();
+
+    let mut device = MockInterruptDevice::new();
+    device.trigger_interrupt();
+    cpu.memory_mut()
+        .add_device(0xD000, Box::new(device))
+        .unwrap();
+
+    cpu.memory_mut().write(0xC000, 0x40); // RTI
+    cpu
+}
+
+#[test]
+fn test_cli_delays_pending_irq_by_one_instruction() {
+    let mut cpu = create_cpu_with_pending_irq();
+
+    cpu.memory_mut().write(0x8000, 0x58); // CLI
+    cpu.memory_mut().write(0x8001, 0xEA); // NOP
+    cpu.memory_mut().write(0x8002, 0xEA); // NOP
+
+    // CLI: the IRQ poll still sees I set
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0x8001);
+    assert!(!cpu.flag_i());
+
+    // NOP runs, then the IRQ is taken
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0xC000);
+
+    // Return address is the instruction after the NOP
+    assert_eq!(cpu.memory_mut().read(0x01FD), 0x80);
+    assert_eq!(cpu.memory_mut().read(0x01FC), 0x02);
+}
+
+#[test]
+fn test_sei_still_takes_pending_irq() {
+    let mut cpu = create_cpu_with_pending_irq();
+    cpu.set_flag_i(false);
+
+    cpu.memory_mut().write(0x8000, 0x78); // SEI
+    cpu.memory_mut().write(0x8001, 0xEA); // NOP
+
+    // SEI: the IRQ poll still sees I clear, so the IRQ is taken
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0xC000);
+    assert_eq!(cpu.cycles(), 2 + 7);
+
+    // The pushed status already has I set
+    let pushed_status = cpu.memory_mut().read(0x01FB);
+    assert_ne!(pushed_status & 0x04, 0);
+}
+
+#[test]
+fn test_plp_clearing_i_delays_pending_irq() {
+    let mut cpu = create_cpu_with_pending_irq();
+
+    // Status with I clear on the stack
+    cpu.memory_mut().write(0x01FD, 0x00);
+    cpu.set_sp(0xFC);
+
+    cpu.memory_mut().write(0x8000, 0x28); // PLP
+    cpu.memory_mut().write(0x8001, 0xEA); // NOP
+
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0x8001);
+    assert!(!cpu.flag_i());
+
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0xC000);
+}
+
+#[test]
+fn test_rti_clearing_i_takes_pending_irq_immediately() {
+    let mut cpu = create_cpu_with_pending_irq();
+
+    // RTI frame returning to 0x9000 with I clear
+    cpu.memory_mut().write(0x01FB, 0x00); // Status
+    cpu.memory_mut().write(0x01FC, 0x00); // PC low
+    cpu.memory_mut().write(0x01FD, 0x90); // PC high
+    cpu.set_sp(0xFA);
+
+    cpu.memory_mut().write(0x8000, 0x40); // RTI
+
+    // RTI restores I before the IRQ poll, so the IRQ is taken at once
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0xC000);
+    assert_eq!(cpu.memory_mut().read(0x01FD), 0x90);
+    assert_eq!(cpu.memory_mut().read(0x01FC), 0x00);
+}
+
+// ========== NMI Edge Detection ==========
+
+/// Mock device that drives the NMI line from a test-controlled level.
+struct MockNmiDevice {
+    line: bool,
+}
+
+impl Device for MockNmiDevice {
+    fn read(&self, _offset: u16) -> u8 {
+        0x00
+    }
+
+    fn write(&mut self, _offset: u16, _value: u8) {}
+
+    fn size(&self) -> u16 {
+        1
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn has_nmi(&self) -> bool {
+        self.line
+    }
+}
+
+/// Create a test CPU with a shared NMI device at 0xD100, an NMI handler
+/// (RTI) at 0xB000 and NOPs at 0x8000.
+fn create_cpu_with_nmi_device() -> (CPU<MappedMemory>, Rc<RefCell<MockNmiDevice>>) {
+    let mut cpu = create_test_cpu();
+
+    let device = Rc::new(RefCell::new(MockNmiDevice { line: false }));
+    cpu.memory_mut()
+        .add_shared_device(0xD100, device.clone())
+        .unwrap();
+
+    // Set NMI vector to 0xB000
+    cpu.memory_mut().write(0xFFFA, 0x00);
+    cpu.memory_mut().write(0xFFFB, 0xB0);
+    cpu.memory_mut().write(0xB000, 0x40); // RTI
+
+    for addr in 0x8000..0x8010 {
+        cpu.memory_mut().write(addr, 0xEA); // NOP
+    }
+
+    (cpu, device)
+}
+
+#[test]
+fn test_nmi_serviced_on_edge_even_with_i_flag_set() {
+    let (mut cpu, device) = create_cpu_with_nmi_device();
+    assert!(cpu.flag_i());
+
+    device.borrow_mut().line = true;
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.pc(), 0xB000);
+    assert_eq!(cpu.cycles(), 2 + 7);
+
+    // Return address and status with B clear
+    assert_eq!(cpu.memory_mut().read(0x01FD), 0x80);
+    assert_eq!(cpu.memory_mut().read(0x01FC), 0x01);
+    assert_eq!(cpu.memory_mut().read(0x01FB) & 0x10, 0);
+}
+
+#[test]
+fn test_nmi_held_active_fires_once() {
+    let (mut cpu, device) = create_cpu_with_nmi_device();
+
+    device.borrow_mut().line = true;
+    cpu.step().unwrap(); // NOP, then NMI
+    assert_eq!(cpu.pc(), 0xB000);
+
+    cpu.step().unwrap(); // RTI
+    assert_eq!(cpu.pc(), 0x8001);
+
+    // Line still active, but no new edge
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0x8002);
+}
+
+#[test]
+fn test_nmi_retriggers_after_line_released() {
+    let (mut cpu, device) = create_cpu_with_nmi_device();
+
+    device.borrow_mut().line = true;
+    cpu.step().unwrap(); // NOP, then NMI
+    cpu.step().unwrap(); // RTI
+    assert_eq!(cpu.pc(), 0x8001);
+
+    device.borrow_mut().line = false;
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0x8002);
+
+    device.borrow_mut().line = true;
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0xB000);
+}
+
+#[test]
+fn test_nmi_has_priority_over_irq() {
+    let (mut cpu, device) = create_cpu_with_nmi_device();
+    cpu.set_flag_i(false);
+
+    let mut irq_device = MockInterruptDevice::new();
+    irq_device.trigger_interrupt();
+    cpu.memory_mut()
+        .add_device(0xD000, Box::new(irq_device))
+        .unwrap();
+    cpu.memory_mut().write(0xC000, 0x40); // RTI
+
+    device.borrow_mut().line = true;
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0xB000);
+
+    // The NMI sequence set I, so the IRQ waits until RTI clears it again
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0xC000);
+}
+
+#[test]
+fn test_trigger_nmi_without_device() {
+    let (mut cpu, _device) = create_cpu_with_nmi_device();
+
+    cpu.trigger_nmi();
+    assert!(cpu.nmi_pending());
+
+    cpu.step().unwrap();
+    assert_eq!(cpu.pc(), 0xB000);
+    assert!(!cpu.nmi_pending());
+}
@@ -52,26 +52,23 @@ fn test_documented_opcodes_have_nonzero_cycles() {
 }
 
 #[test]
-fn test_illegal_opcodes_marked() {
-    // Illegal opcodes should be marked with "???" and 0 cycles
-    let mut illegal_count = 0;
+fn test_jam_opcodes_marked() {
+    // JAM opcodes should be marked with "???" and 0 cycles
+    let mut jam_count = 0;
 
     for (opcode, metadata) in OPCODE_TABLE.iter().enumerate() {
         if metadata.mnemonic == "???" {
-            illegal_count += 1;
+            jam_count += 1;
             assert_eq!(
                 metadata.base_cycles, 0,
-                "Illegal opcode 0x{:02X} should have 0 cycles",
+                "JAM opcode 0x{:02X} should have 0 cycles",
                 opcode
             );
         }
     }
 
-    // Should have 105 illegal opcodes (256 - 151 documented)
-    assert!(
-        illegal_count > 0,
-        "Should have at least some illegal opcodes marked"
-    );
+    // 151 documented + 93 undocumented opcodes leave the 12 JAM opcodes
+    assert_eq!(jam_count, 12, "Only the JAM opcodes should be marked");
 }
 
 #[test]
@@ -806,6 +803,15 @@ fn test_implemented_opcodes() {
                 "Opcode 0x{:02X} should be TYA mnemonic",
                 opcode
             );
+        } else if metadata.illegal {
+            // Undocumented opcodes are implemented, except the JAM opcodes
+            assert_eq!(
+                metadata.implemented,
+                metadata.mnemonic != "???",
+                "Illegal opcode 0x{:02X} ({}) has unexpected implementation status",
+                opcode,
+                metadata.mnemonic
+            );
         } else {
             assert!(
                 !metadata.implemented,
@@ -915,11 +921,13 @@ fn test_instruction_variety() {
 
 #[test]
 fn test_cycle_cost_range() {
-    // Verify cycle costs are in reasonable range (1-7 for documented instructions)
+    // Verify cycle costs are in reasonable range (1-7 for documented instructions,
+    // up to 8 for illegal read-modify-write opcodes)
     for (opcode, metadata) in OPCODE_TABLE.iter().enumerate() {
         if metadata.mnemonic != "???" {
+            let max_cycles = if metadata.illegal { 8 } else { 7 };
             assert!(
-                metadata.base_cycles >= 1 && metadata.base_cycles <= 7,
+                metadata.base_cycles >= 1 && metadata.base_cycles <= max_cycles,
                 "Opcode 0x{:02X} ({}) has unusual cycle cost: {}",
                 opcode,
                 metadata.mnemonic,
@@ -6,6 +6,7 @@
 //! - No panics on malformed input
 //! - Symbol resolution correctness
 
+use lib6502::opcodes::has_canonical_encoding;
 use lib6502::{assemble, OPCODE_TABLE};
 use proptest::prelude::*;
 
@@ -196,7 +197,8 @@ proptest! {
     fn prop_implemented_opcodes_assemble_correctly(opcode_idx in 0usize..256usize) {
         let metadata = &OPCODE_TABLE[opcode_idx];
 
-        if !metadata.implemented {
+        // Illegal duplicates assemble to the canonical encoding instead
+        if !metadata.implemented || !has_canonical_encoding(opcode_idx as u8) {
             return Ok(());
         }
 
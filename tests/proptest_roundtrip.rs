@@ -3,8 +3,9 @@
 //! These tests verify that:
 //! - assemble(disassemble(bytes)) == bytes for valid instruction sequences
 //! - disassemble(assemble(source)) preserves semantic meaning
-//! - All 151 implemented opcodes round-trip correctly
+//! - All implemented opcodes with a canonical encoding round-trip correctly
 
+use lib6502::opcodes::has_canonical_encoding;
 use lib6502::{assemble, disassemble, AddressingMode, DisassemblyOptions, OPCODE_TABLE};
 use proptest::prelude::*;
 
@@ -12,7 +13,8 @@ use proptest::prelude::*;
 fn generate_instruction_bytes(opcode: u8) -> Vec<u8> {
     let metadata = &OPCODE_TABLE[opcode as usize];
 
-    if !metadata.implemented || metadata.mnemonic == "???" {
+    // Illegal duplicates disassemble as .byte and cannot round-trip as instructions
+    if !metadata.implemented || !has_canonical_encoding(opcode) {
         return vec![];
     }
 
@@ -37,7 +39,7 @@ fn implemented_opcodes() -> Vec<u8> {
     OPCODE_TABLE
         .iter()
         .enumerate()
-        .filter(|(_, m)| m.implemented && m.mnemonic != "???")
+        .filter(|&(i, m)| m.implemented && has_canonical_encoding(i as u8))
         .map(|(i, _)| i as u8)
         .collect()
 }
@@ -47,9 +49,11 @@ fn roundtrippable_opcodes() -> Vec<u8> {
     OPCODE_TABLE
         .iter()
         .enumerate()
-        .filter(|(_, m)| {
-            m.implemented && m.mnemonic != "???" && m.addressing_mode != AddressingMode::Relative
-            // Branches need labels
+        .filter(|&(i, m)| {
+            // Branches need labels; illegal duplicates disassemble as .byte
+            m.implemented
+                && has_canonical_encoding(i as u8)
+                && m.addressing_mode != AddressingMode::Relative
         })
         .map(|(i, _)| i as u8)
         .collect()
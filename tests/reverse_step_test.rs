@@ -0,0 +1,188 @@
+//! Tests for debugger reverse stepping.
+//!
+//! Tests cover:
+//! - Registers, flags and cycles are restored
+//! - Memory writes are undone, including stack pushes
+//! - The history is bounded by its depth
+//! - Interrupt entry is undone with the instruction it followed
+//! - Writes made outside a step are not undone
+//! - Device registers outside the RAM ranges are never read or replayed
+
+use lib6502::debugger::{JournaledMemory, ReverseStepper};
+use lib6502::{FlatMemory, MappedMemory, MemoryBus, RamDevice, RomDevice, Uart6551, CPU};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Helper function to create a CPU with reset vector at 0x8000
+fn setup_cpu() -> CPU<JournaledMemory<FlatMemory>> {
+    let mut memory = FlatMemory::new();
+    memory.write(0xFFFC, 0x00);
+    memory.write(0xFFFD, 0x80);
+    CPU::new(JournaledMemory::new(memory, [0x0000..=0xFFFF]))
+}
+
+fn load(cpu: &mut CPU<JournaledMemory<FlatMemory>>, addr: u16, bytes: &[u8]) {
+    for (i, &byte) in bytes.iter().enumerate() {
+        cpu.memory_mut().inner_mut().write(addr + i as u16, byte);
+    }
+}
+
+#[test]
+fn test_step_back_restores_registers_and_flags() {
+    let mut cpu = setup_cpu();
+
+    // LDA #$80 / SEC / TAX
+    load(&mut cpu, 0x8000, &[0xA9, 0x80, 0x38, 0xAA]);
+
+    let mut stepper = ReverseStepper::new(16);
+    for _ in 0..3 {
+        stepper.step(&mut cpu).unwrap();
+    }
+    assert_eq!(cpu.x(), 0x80);
+    assert!(cpu.flag_c());
+
+    assert_eq!(stepper.step_back(&mut cpu, 2), 2);
+    assert_eq!(cpu.pc(), 0x8002);
+    assert_eq!(cpu.a(), 0x80);
+    assert_eq!(cpu.x(), 0x00);
+    assert!(cpu.flag_n());
+    assert!(!cpu.flag_c());
+    assert_eq!(cpu.cycles(), 2);
+
+    // Re-executing from the restored state gives the same result
+    stepper.step(&mut cpu).unwrap();
+    stepper.step(&mut cpu).unwrap();
+    assert_eq!(cpu.x(), 0x80);
+    assert_eq!(cpu.cycles(), 6);
+}
+
+#[test]
+fn test_step_back_undoes_memory_and_stack_writes() {
+    let mut cpu = setup_cpu();
+    cpu.memory_mut().inner_mut().write(0x0200, 0x55);
+
+    // LDA #$AA / STA $0200 / JSR $9000
+    load(
+        &mut cpu,
+        0x8000,
+        &[0xA9, 0xAA, 0x8D, 0x00, 0x02, 0x20, 0x00, 0x90],
+    );
+
+    let mut stepper = ReverseStepper::new(16);
+    for _ in 0..3 {
+        stepper.step(&mut cpu).unwrap();
+    }
+    assert_eq!(cpu.pc(), 0x9000);
+    assert_eq!(cpu.memory().read(0x0200), 0xAA);
+    assert_eq!(cpu.memory().read(0x01FD), 0x80);
+
+    assert_eq!(stepper.step_back(&mut cpu, 3), 3);
+    assert_eq!(cpu.pc(), 0x8000);
+    assert_eq!(cpu.sp(), 0xFD);
+    assert_eq!(cpu.memory().read(0x0200), 0x55);
+    assert_eq!(cpu.memory().read(0x01FD), 0x00);
+    assert_eq!(cpu.memory().read(0x01FC), 0x00);
+    assert!(stepper.is_empty());
+}
+
+#[test]
+fn test_history_is_bounded_by_depth() {
+    let mut cpu = setup_cpu();
+
+    // INX x 5
+    load(&mut cpu, 0x8000, &[0xE8; 5]);
+
+    let mut stepper = ReverseStepper::new(3);
+    for _ in 0..5 {
+        stepper.step(&mut cpu).unwrap();
+    }
+    assert_eq!(stepper.len(), 3);
+
+    // Only the last three instructions can be undone
+    assert_eq!(stepper.step_back(&mut cpu, 10), 3);
+    assert_eq!(cpu.x(), 2);
+    assert_eq!(cpu.pc(), 0x8002);
+}
+
+#[test]
+fn test_step_back_undoes_interrupt_entry() {
+    let mut cpu = setup_cpu();
+
+    // NMI vector -> $9000, NOP at $8000
+    load(&mut cpu, 0xFFFA, &[0x00, 0x90]);
+    load(&mut cpu, 0x8000, &[0xEA]);
+
+    let mut stepper = ReverseStepper::new(16);
+    cpu.trigger_nmi();
+    stepper.step(&mut cpu).unwrap();
+    assert_eq!(cpu.pc(), 0x9000);
+
+    stepper.step_back(&mut cpu, 1);
+    assert_eq!(cpu.pc(), 0x8000);
+    assert_eq!(cpu.sp(), 0xFD);
+    assert!(cpu.nmi_pending());
+    assert_eq!(cpu.memory().read(0x01FD), 0x00);
+}
+
+#[test]
+fn test_writes_outside_step_are_kept() {
+    let mut cpu = setup_cpu();
+
+    // STA $0301
+    load(&mut cpu, 0x8000, &[0x8D, 0x01, 0x03]);
+
+    let mut stepper = ReverseStepper::new(16);
+    cpu.memory_mut().write(0x0301, 0x77);
+    stepper.step(&mut cpu).unwrap();
+    assert_eq!(cpu.memory().read(0x0301), 0x00);
+
+    // Undo restores the value from before the step, not before the poke
+    stepper.step_back(&mut cpu, 1);
+    assert_eq!(cpu.memory().read(0x0301), 0x77);
+}
+
+#[test]
+fn test_step_back_with_empty_history() {
+    let mut cpu = setup_cpu();
+    let mut stepper = ReverseStepper::new(16);
+
+    assert_eq!(stepper.step_back(&mut cpu, 1), 0);
+    assert_eq!(cpu.pc(), 0x8000);
+}
+
+#[test]
+fn test_device_writes_are_not_journaled() {
+    let mut memory = MappedMemory::new();
+    memory
+        .add_device(0x0000, Box::new(RamDevice::new(0x8000)))
+        .unwrap();
+    // LDA #$42 / STA $8000 / STA $10, reset vector $C000
+    let mut rom = vec![0xEA; 0x4000];
+    rom[..7].copy_from_slice(&[0xA9, 0x42, 0x8D, 0x00, 0x80, 0x85, 0x10]);
+    rom[0x3FFC] = 0x00;
+    rom[0x3FFD] = 0xC0;
+    memory
+        .add_device(0xC000, Box::new(RomDevice::new(rom)))
+        .unwrap();
+
+    let transmitted = Rc::new(RefCell::new(Vec::new()));
+    let transmitted_clone = Rc::clone(&transmitted);
+    let mut uart = Uart6551::new();
+    uart.set_transmit_callback(move |byte| transmitted_clone.borrow_mut().push(byte));
+    uart.receive_byte(0x41);
+    memory.add_device(0x8000, Box::new(uart)).unwrap();
+
+    let mut cpu = CPU::new(JournaledMemory::new(memory, [0x0000..=0x7FFF]));
+    let mut stepper = ReverseStepper::new(16);
+    for _ in 0..3 {
+        stepper.step(&mut cpu).unwrap();
+    }
+    assert_eq!(*transmitted.borrow(), vec![0x42]);
+
+    assert_eq!(stepper.step_back(&mut cpu, 3), 3);
+    assert_eq!(cpu.memory().read(0x0010), 0x00);
+
+    // Undo neither retransmitted anything nor consumed the pending RX byte
+    assert_eq!(*transmitted.borrow(), vec![0x42]);
+    assert_eq!(cpu.memory().read(0x8000), 0x41);
+}
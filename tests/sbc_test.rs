@@ -737,3 +737,43 @@ fn test_sbc_decimal_vs_binary_mode() {
     cpu.step().unwrap();
     assert_eq!(cpu.a(), 0x25); // BCD result: 50 - 25 = 25
 }
+
+#[test]
+fn test_sbc_decimal_mode_flags_follow_binary_result() {
+    let mut cpu = setup_cpu();
+
+    // SBC #$01
+    cpu.memory_mut().write(0x8000, 0xE9);
+    cpu.memory_mut().write(0x8001, 0x01);
+
+    cpu.set_a(0x00);
+    cpu.set_flag_c(true);
+    cpu.set_flag_d(true);
+
+    cpu.step().unwrap();
+
+    // 00 - 01 = 99 BCD; NMOS N/Z/V come from the binary result $FF
+    assert_eq!(cpu.a(), 0x99);
+    assert!(!cpu.flag_c());
+    assert!(cpu.flag_n());
+    assert!(!cpu.flag_z());
+    assert!(!cpu.flag_v());
+}
+
+#[test]
+fn test_sbc_decimal_mode_disabled_uses_binary() {
+    let mut cpu = setup_cpu();
+
+    // SBC #$25
+    cpu.memory_mut().write(0x8000, 0xE9);
+    cpu.memory_mut().write(0x8001, 0x25);
+
+    cpu.set_decimal_mode_enabled(false);
+    cpu.set_a(0x50);
+    cpu.set_flag_c(true);
+    cpu.set_flag_d(true);
+
+    cpu.step().unwrap();
+
+    assert_eq!(cpu.a(), 0x2B); // Binary result, D flag ignored
+}
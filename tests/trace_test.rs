@@ -0,0 +1,113 @@
+//! Tests for the debugger instruction tracer.
+//!
+//! Tests cover:
+//! - Entries capture registers and cycles before execution
+//! - The ring buffer keeps only the most recent entries
+//! - PC range filtering
+//! - Failing instructions are still recorded
+
+use lib6502::debugger::Tracer;
+use lib6502::{FlatMemory, MemoryBus, CPU};
+
+/// Helper function to create a CPU with reset vector at 0x8000
+fn setup_cpu() -> CPU<FlatMemory> {
+    let mut memory = FlatMemory::new();
+    memory.write(0xFFFC, 0x00);
+    memory.write(0xFFFD, 0x80);
+    CPU::new(memory)
+}
+
+#[test]
+fn test_trace_captures_state_before_execution() {
+    let mut cpu = setup_cpu();
+
+    // $8000: LDX #$10
+    // $8002: STX $2000
+    cpu.memory_mut().write(0x8000, 0xA2);
+    cpu.memory_mut().write(0x8001, 0x10);
+    cpu.memory_mut().write(0x8002, 0x8E);
+    cpu.memory_mut().write(0x8003, 0x00);
+    cpu.memory_mut().write(0x8004, 0x20);
+
+    let mut tracer = Tracer::new(8);
+    tracer.step(&mut cpu).unwrap();
+    tracer.step(&mut cpu).unwrap();
+
+    let entries: Vec<_> = tracer.entries().copied().collect();
+    assert_eq!(entries.len(), 2);
+
+    assert_eq!(entries[0].pc, 0x8000);
+    assert_eq!(entries[0].x, 0x00);
+    assert_eq!(entries[0].cycles, 0);
+    assert_eq!(entries[0].mnemonic(), "LDX");
+
+    assert_eq!(entries[1].opcode, 0x8E);
+    assert_eq!(entries[1].operands, [0x00, 0x20]);
+    assert_eq!(entries[1].size_bytes, 3);
+    assert_eq!(entries[1].x, 0x10);
+    assert_eq!(entries[1].cycles, 2);
+    assert_eq!(entries[1].sp, 0xFD);
+    assert_eq!(entries[1].status, cpu.status());
+    assert_eq!(entries[1].disassembly(), "STX $2000");
+}
+
+#[test]
+fn test_trace_ring_buffer_depth() {
+    let mut cpu = setup_cpu();
+
+    for addr in 0x8000..0x8010 {
+        cpu.memory_mut().write(addr, 0xEA); // NOP
+    }
+
+    let mut tracer = Tracer::new(4);
+    for _ in 0..16 {
+        tracer.step(&mut cpu).unwrap();
+    }
+
+    let pcs: Vec<u16> = tracer.entries().map(|e| e.pc).collect();
+    assert_eq!(pcs, vec![0x800C, 0x800D, 0x800E, 0x800F]);
+
+    tracer.clear();
+    assert!(tracer.is_empty());
+}
+
+#[test]
+fn test_trace_pc_filter() {
+    let mut cpu = setup_cpu();
+
+    // $8000: JSR $9000
+    // $8003: NOP
+    // $9000: NOP
+    // $9001: RTS
+    cpu.memory_mut().write(0x8000, 0x20);
+    cpu.memory_mut().write(0x8001, 0x00);
+    cpu.memory_mut().write(0x8002, 0x90);
+    cpu.memory_mut().write(0x8003, 0xEA);
+    cpu.memory_mut().write(0x9000, 0xEA);
+    cpu.memory_mut().write(0x9001, 0x60);
+
+    let mut tracer = Tracer::new(16);
+    tracer.set_pc_filter(Some(0x9000..=0x90FF));
+    for _ in 0..4 {
+        tracer.step(&mut cpu).unwrap();
+    }
+
+    let mnemonics: Vec<&str> = tracer.entries().map(|e| e.mnemonic()).collect();
+    assert_eq!(mnemonics, vec!["NOP", "RTS"]);
+    assert_eq!(tracer.len(), 2);
+}
+
+#[test]
+fn test_trace_records_failing_instruction() {
+    let mut cpu = setup_cpu();
+
+    cpu.memory_mut().write(0x8000, 0x02); // JAM
+
+    let mut tracer = Tracer::new(4);
+    assert!(tracer.step(&mut cpu).is_err());
+
+    let last = tracer.entries().last().unwrap();
+    assert_eq!(last.pc, 0x8000);
+    assert_eq!(last.mnemonic(), "???");
+    assert_eq!(last.disassembly(), ".byte $02");
+}
@@ -0,0 +1,119 @@
+//! Tests for the debugger hang watchdog.
+//!
+//! Tests cover:
+//! - Self-loops with interrupts disabled are reported
+//! - An NMI still breaks a reported self-loop
+//! - Self-loops with interrupts enabled are left alone (wait-for-IRQ idiom)
+//! - Execution failures are reported with the offending opcode
+//! - History depth is bounded
+
+use lib6502::debugger::{HangReason, Watchdog};
+use lib6502::{ExecutionError, FlatMemory, MemoryBus, CPU};
+
+/// Helper function to create a CPU with reset vector at 0x8000
+fn setup_cpu() -> CPU<FlatMemory> {
+    let mut memory = FlatMemory::new();
+    memory.write(0xFFFC, 0x00);
+    memory.write(0xFFFD, 0x80);
+    CPU::new(memory)
+}
+
+#[test]
+fn test_watchdog_branch_to_self_with_interrupts_disabled() {
+    let mut cpu = setup_cpu();
+
+    // $8000: SEC
+    // $8001: BCS $8001 (offset -2)
+    cpu.memory_mut().write(0x8000, 0x38);
+    cpu.memory_mut().write(0x8001, 0xB0);
+    cpu.memory_mut().write(0x8002, 0xFE);
+
+    let mut watchdog = Watchdog::new(8);
+    assert!(watchdog.step(&mut cpu).is_none());
+
+    let report = watchdog.step(&mut cpu).expect("branch to self should hang");
+    assert_eq!(report.reason, HangReason::SelfLoopWithInterruptsDisabled);
+    assert_eq!(report.pc, 0x8001);
+    assert_eq!(report.cycles, cpu.cycles());
+}
+
+#[test]
+fn test_watchdog_self_loop_is_broken_by_nmi() {
+    let mut cpu = setup_cpu();
+
+    // $8000: JMP $8000 (I flag set after reset), NMI handler at $9000
+    cpu.memory_mut().write(0x8000, 0x4C);
+    cpu.memory_mut().write(0x8001, 0x00);
+    cpu.memory_mut().write(0x8002, 0x80);
+    cpu.memory_mut().write(0xFFFA, 0x00);
+    cpu.memory_mut().write(0xFFFB, 0x90);
+
+    let mut watchdog = Watchdog::new(8);
+    let report = watchdog.step(&mut cpu).expect("jump to self should hang");
+    assert_eq!(report.reason, HangReason::SelfLoopWithInterruptsDisabled);
+
+    cpu.trigger_nmi();
+    assert!(watchdog.step(&mut cpu).is_none());
+    assert_eq!(cpu.pc(), 0x9000);
+}
+
+#[test]
+fn test_watchdog_allows_wait_for_interrupt_loop() {
+    let mut cpu = setup_cpu();
+
+    // $8000: CLI
+    // $8001: JMP $8001
+    cpu.memory_mut().write(0x8000, 0x58);
+    cpu.memory_mut().write(0x8001, 0x4C);
+    cpu.memory_mut().write(0x8002, 0x01);
+    cpu.memory_mut().write(0x8003, 0x80);
+
+    let mut watchdog = Watchdog::new(8);
+    for _ in 0..10 {
+        assert!(watchdog.step(&mut cpu).is_none());
+    }
+    assert_eq!(cpu.pc(), 0x8001);
+}
+
+#[test]
+fn test_watchdog_reports_execution_failure() {
+    let mut cpu = setup_cpu();
+
+    // $8000: NOP
+    // $8001: $02 (not implemented)
+    cpu.memory_mut().write(0x8000, 0xEA);
+    cpu.memory_mut().write(0x8001, 0x02);
+
+    let mut watchdog = Watchdog::new(8);
+    assert!(watchdog.step(&mut cpu).is_none());
+
+    let report = watchdog.step(&mut cpu).expect("unimplemented opcode");
+    assert_eq!(
+        report.reason,
+        HangReason::ExecutionFailed(ExecutionError::UnimplementedOpcode(0x02))
+    );
+    assert_eq!(report.pc, 0x8001);
+    assert_eq!(report.recent_pcs, vec![0x8000, 0x8001]);
+}
+
+#[test]
+fn test_watchdog_history_is_bounded() {
+    let mut cpu = setup_cpu();
+
+    // Eight NOPs followed by JMP to self
+    for addr in 0x8000..0x8008 {
+        cpu.memory_mut().write(addr, 0xEA);
+    }
+    cpu.memory_mut().write(0x8008, 0x4C);
+    cpu.memory_mut().write(0x8009, 0x08);
+    cpu.memory_mut().write(0x800A, 0x80);
+
+    let mut watchdog = Watchdog::new(3);
+    let report = loop {
+        if let Some(report) = watchdog.step(&mut cpu) {
+            break report;
+        }
+    };
+
+    assert_eq!(report.recent_pcs, vec![0x8006, 0x8007, 0x8008]);
+}